@@ -2,6 +2,7 @@ use version_check::is_feature_flaggable;
 
 fn main() {
     println!("cargo::rustc-check-cfg=cfg(nightly)");
+    println!("cargo::rustc-check-cfg=cfg(tokio_unstable)");
 
     if is_feature_flaggable() == Some(true) {
         println!("cargo:rustc-cfg=nightly");
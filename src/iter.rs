@@ -0,0 +1,1684 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{Display, Write},
+    future::poll_fn,
+    hash::Hash,
+    iter::{Peekable, Skip, StepBy, Sum},
+    ops::ControlFlow,
+    pin::Pin,
+    sync::atomic::{AtomicUsize, Ordering},
+    task::Poll,
+};
+use thiserror::Error;
+#[cfg(feature = "stream")]
+use {
+    futures_core::Stream,
+    std::task::Context,
+    tokio::{
+        sync::mpsc::Receiver,
+        time::{Duration, Instant, Sleep, sleep_until},
+    },
+};
+
+/// A value of one of two possible types (used by [`IterExt::partition_map`]).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Either<L, R> {
+    /// A value of the left type.
+    Left(L),
+    /// A value of the right type.
+    Right(R),
+}
+
+/// Error returned by [`IterExt::exactly_one`].
+#[derive(Error, Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ExactlyOneError<T> {
+    #[error("exactly_one: the iterator is empty")]
+    /// The iterator is empty.
+    Empty,
+    #[error("exactly_one: the iterator has more than one element")]
+    /// The iterator has more than one element, carrying the first two elements.
+    MoreThanOne(T, T),
+}
+
+/// An iterator that collapses consecutive equal items into the item and the length of its run
+/// (run-length encoding).
+///
+/// Use [`IterExt::dedup_count`] to construct.
+#[derive(Debug, Clone)]
+pub struct DedupCount<I: Iterator> {
+    iter: I,
+    pending: Option<I::Item>,
+}
+
+impl<I> Iterator for DedupCount<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = (I::Item, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.pending.take().or_else(|| self.iter.next())?;
+        let mut count = 1;
+
+        loop {
+            match self.iter.next() {
+                Some(next) if next == item => count += 1,
+                next => {
+                    self.pending = next;
+                    return Some((item, count));
+                }
+            }
+        }
+    }
+}
+
+/// An iterator that yields the items of the underlying iterator in owned chunks.
+///
+/// Use [`IterExt::chunks_owned`] to construct.
+#[derive(Debug, Clone)]
+pub struct ChunksOwned<I> {
+    iter: I,
+    size: usize,
+}
+
+impl<I: Iterator> Iterator for ChunksOwned<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk: Vec<_> = self.iter.by_ref().take(self.size).collect();
+
+        if chunk.is_empty() { None } else { Some(chunk) }
+    }
+}
+
+/// An iterator that yields each distinct item the first time it is seen.
+///
+/// Use [`IterExt::unique`] to construct.
+#[derive(Debug, Clone)]
+pub struct Unique<I: Iterator> {
+    iter: I,
+    seen: HashSet<I::Item>,
+}
+
+impl<I> Iterator for Unique<I>
+where
+    I: Iterator,
+    I::Item: Hash + Eq + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find(|item| self.seen.insert(item.clone()))
+    }
+}
+
+/// An iterator that groups consecutive items sharing the same key.
+///
+/// Use [`IterExt::group_consecutive_by`] to construct.
+#[derive(Debug, Clone)]
+pub struct GroupConsecutiveBy<I: Iterator, K, F> {
+    iter: I,
+    key: F,
+    pending: Option<(K, I::Item)>,
+}
+
+impl<I, K, F> Iterator for GroupConsecutiveBy<I, K, F>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (K, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, first) = match self.pending.take() {
+            Some(pending) => pending,
+            None => {
+                let item = self.iter.next()?;
+                ((self.key)(&item), item)
+            }
+        };
+        let mut group = vec![first];
+
+        for item in self.iter.by_ref() {
+            let next_key = (self.key)(&item);
+
+            if next_key != key {
+                self.pending = Some((next_key, item));
+                break;
+            }
+
+            group.push(item);
+        }
+
+        Some((key, group))
+    }
+}
+
+/// An iterator that inserts a separator between the items of the underlying iterator.
+///
+/// Use [`IterExt::intersperse`] to construct.
+#[derive(Debug, Clone)]
+pub struct Intersperse<I: Iterator> {
+    iter: Peekable<I>,
+    sep: I::Item,
+    needs_sep: bool,
+}
+
+impl<I> Iterator for Intersperse<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needs_sep && self.iter.peek().is_some() {
+            self.needs_sep = false;
+            Some(self.sep.clone())
+        } else {
+            self.needs_sep = true;
+            self.iter.next()
+        }
+    }
+}
+
+/// An iterator that yields overlapping windows of consecutive items.
+///
+/// Use [`IterExt::windowed`] to construct.
+#[derive(Debug, Clone)]
+pub struct Windowed<I: Iterator> {
+    iter: I,
+    window: VecDeque<I::Item>,
+    size: usize,
+}
+
+impl<I> Iterator for Windowed<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.window.len() == self.size {
+            self.window.pop_front();
+        }
+
+        while self.window.len() < self.size {
+            self.window.push_back(self.iter.next()?);
+        }
+
+        Some(self.window.iter().cloned().collect())
+    }
+}
+
+/// An iterator that yields all the pairs of the items of two iterators.
+///
+/// Use [`IterExt::cartesian_product`] to construct.
+#[derive(Debug, Clone)]
+pub struct CartesianProduct<I: Iterator, J> {
+    iter: I,
+    item: Option<I::Item>,
+    other: J,
+    other_orig: J,
+}
+
+impl<I, J> Iterator for CartesianProduct<I, J>
+where
+    I: Iterator,
+    I::Item: Clone,
+    J: Iterator + Clone,
+{
+    type Item = (I::Item, J::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = &self.item {
+                if let Some(other) = self.other.next() {
+                    return Some((item.clone(), other));
+                }
+            }
+
+            self.item = Some(self.iter.next()?);
+            self.other = self.other_orig.clone();
+        }
+    }
+}
+
+/// An iterator that increments a counter for each item yielded.
+///
+/// Use [`IterExt::count_into`] to construct.
+#[derive(Debug, Clone)]
+pub struct CountInto<'a, I> {
+    iter: I,
+    counter: &'a AtomicUsize,
+}
+
+impl<I: Iterator> Iterator for CountInto<'_, I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.counter.fetch_add(1, Ordering::Relaxed);
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// [`Iterator`] extension trait.
+///
+/// This trait has been implemented for all [`Sized`] `Iterator`s.
+pub trait IterExt: Iterator + Sized {
+    /// Consume the iterator, counting the occurrences of each distinct item.
+    ///
+    /// This is the counterpart of tallying a slice for arbitrary (non-slice) iterators, which
+    /// only needs to traverse the iterator once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// let counts = "a b a c a b".split(' ').counts();
+    /// assert_eq!(counts["a"], 3);
+    /// assert_eq!(counts["b"], 2);
+    /// assert_eq!(counts["c"], 1);
+    /// ```
+    fn counts(self) -> HashMap<Self::Item, usize>
+    where
+        Self::Item: Hash + Eq,
+    {
+        let mut counts = HashMap::new();
+
+        for item in self {
+            *counts.entry(item).or_insert(0) += 1;
+        }
+
+        counts
+    }
+
+    /// Collect an iterator of [`Result`]s into a collection, short-circuiting on the first `Err`.
+    ///
+    /// This is equivalent to `collect::<Result<C, E>>()`, but reads better and documents the
+    /// intent.
+    ///
+    /// Note: The standard library has an unstable [`Iterator::try_collect`] with the same name,
+    /// so calling this method with the method call syntax triggers the `unstable_name_collisions`
+    /// lint. Call it as `IterExt::try_collect(iter)` to avoid the warning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// let nums: Result<Vec<i32>, _> = IterExt::try_collect(["1", "2", "3"].iter().map(|s| s.parse()));
+    /// assert_eq!(nums.unwrap(), [1, 2, 3]);
+    ///
+    /// let nums: Result<Vec<i32>, _> = IterExt::try_collect(["1", "x", "3"].iter().map(|s| s.parse()));
+    /// assert!(nums.is_err());
+    /// ```
+    fn try_collect<C, T, E>(self) -> Result<C, E>
+    where
+        Self: Iterator<Item = Result<T, E>>,
+        C: FromIterator<T>,
+    {
+        self.collect()
+    }
+
+    /// Find the minimum and maximum items in a single traversal.
+    ///
+    /// Returns `None` if the iterator is empty. If several items are equally minimum, the first
+    /// one is returned as the minimum; if several items are equally maximum, the last one is
+    /// returned as the maximum (consistent with [`Iterator::min`] and [`Iterator::max`]). For a
+    /// single-element iterator, the minimum and maximum are both that element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// assert_eq!([3, 1, 4, 1, 5].into_iter().minmax(), Some((1, 5)));
+    /// assert_eq!([42].into_iter().minmax(), Some((42, 42)));
+    /// assert_eq!(std::iter::empty::<i32>().minmax(), None);
+    /// ```
+    fn minmax(mut self) -> Option<(Self::Item, Self::Item)>
+    where
+        Self::Item: Ord + Clone,
+    {
+        let first = self.next()?;
+        let mut min = first.clone();
+        let mut max = first;
+
+        for item in self {
+            if item < min {
+                min = item;
+            } else if item >= max {
+                max = item;
+            }
+        }
+
+        Some((min, max))
+    }
+
+    /// Construct a [`DedupCount`] iterator that yields the item of each run of consecutive equal
+    /// items along with the length of the run (run-length encoding).
+    ///
+    /// Each run is yielded lazily, as soon as its end is reached.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// let runs: Vec<_> = "aaabcc".chars().dedup_count().collect();
+    /// assert_eq!(runs, [('a', 3), ('b', 1), ('c', 2)]);
+    /// ```
+    fn dedup_count(self) -> DedupCount<Self>
+    where
+        Self::Item: PartialEq,
+    {
+        DedupCount {
+            iter: self,
+            pending: None,
+        }
+    }
+
+    /// Construct a [`ChunksOwned`] iterator that yields the items in [`Vec`]s of `size` items
+    /// (the last chunk may be shorter).
+    ///
+    /// Unlike [`slice::chunks`], this works on any iterator, including streaming sources whose
+    /// items are not `Clone`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// let chunks: Vec<_> = (1..=5).chunks_owned(2).collect();
+    /// assert_eq!(chunks, [vec![1, 2], vec![3, 4], vec![5]]);
+    /// ```
+    fn chunks_owned(self, size: usize) -> ChunksOwned<Self> {
+        assert!(size != 0, "chunks_owned: chunk size must be non-zero");
+
+        ChunksOwned { iter: self, size }
+    }
+
+    /// Format each item with [`Display`] and join them with `sep` into a [`String`].
+    ///
+    /// This avoids collecting into an intermediate `Vec<String>` before calling `join`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// assert_eq!([1, 2, 3].iter().join_to_string(", "), "1, 2, 3");
+    /// assert_eq!([1].iter().join_to_string(", "), "1");
+    /// assert_eq!(std::iter::empty::<i32>().join_to_string(", "), "");
+    /// ```
+    fn join_to_string(self, sep: &str) -> String
+    where
+        Self::Item: Display,
+    {
+        let mut joined = String::new();
+
+        for (i, item) in self.enumerate() {
+            if i != 0 {
+                joined.push_str(sep);
+            }
+
+            write!(joined, "{}", item).expect("Should always success");
+        }
+
+        joined
+    }
+
+    /// Find the first item that has been seen before, consuming the iterator lazily only up to
+    /// that point.
+    ///
+    /// Returns the second occurrence of the first repeated item, or `None` if all the items are
+    /// distinct (in which case the iterator is exhausted).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// assert_eq!([1, 2, 3, 2, 1].into_iter().find_duplicate(), Some(2));
+    /// assert_eq!([1, 2, 3].into_iter().find_duplicate(), None);
+    /// ```
+    fn find_duplicate(self) -> Option<Self::Item>
+    where
+        Self::Item: Hash + Eq,
+    {
+        let mut seen = HashSet::new();
+
+        for item in self {
+            if seen.contains(&item) {
+                return Some(item);
+            }
+
+            seen.insert(item);
+        }
+
+        None
+    }
+
+    /// Construct a [`Unique`] iterator that yields each distinct item the first time it is seen,
+    /// skipping later repeats.
+    ///
+    /// Unlike sorting-based deduplication, this preserves the first-seen order and works lazily.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// let unique: Vec<_> = [3, 1, 3, 2, 1].into_iter().unique().collect();
+    /// assert_eq!(unique, [3, 1, 2]);
+    /// ```
+    fn unique(self) -> Unique<Self>
+    where
+        Self::Item: Hash + Eq + Clone,
+    {
+        Unique {
+            iter: self,
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Route each item into one of two [`Vec`]s, according to the [`Either`] returned by `f`.
+    ///
+    /// This is more expressive than [`Iterator::partition`] since the two sides can have
+    /// different types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::{Either, IterExt};
+    ///
+    /// let (nums, words): (Vec<i32>, Vec<&str>) = ["1", "a", "2", "b"]
+    ///     .into_iter()
+    ///     .partition_map(|s| match s.parse() {
+    ///         Ok(num) => Either::Left(num),
+    ///         Err(_) => Either::Right(s),
+    ///     });
+    /// assert_eq!(nums, [1, 2]);
+    /// assert_eq!(words, ["a", "b"]);
+    /// ```
+    fn partition_map<A, B, F>(self, mut f: F) -> (Vec<A>, Vec<B>)
+    where
+        F: FnMut(Self::Item) -> Either<A, B>,
+    {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+
+        for item in self {
+            match f(item) {
+                Either::Left(a) => left.push(a),
+                Either::Right(b) => right.push(b),
+            }
+        }
+
+        (left, right)
+    }
+
+    /// Returns the only element of the iterator, or an [`ExactlyOneError`] if it is empty or has
+    /// more than one element.
+    ///
+    /// At most two elements are consumed. If there is more than one element, the first two are
+    /// carried by [`ExactlyOneError::MoreThanOne`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::{ExactlyOneError, IterExt};
+    ///
+    /// assert_eq!([1].into_iter().exactly_one(), Ok(1));
+    /// assert_eq!(std::iter::empty::<i32>().exactly_one(), Err(ExactlyOneError::Empty));
+    /// assert_eq!([1, 2, 3].into_iter().exactly_one(), Err(ExactlyOneError::MoreThanOne(1, 2)));
+    /// ```
+    fn exactly_one(mut self) -> Result<Self::Item, ExactlyOneError<Self::Item>> {
+        let first = self.next().ok_or(ExactlyOneError::Empty)?;
+
+        match self.next() {
+            None => Ok(first),
+            Some(second) => Err(ExactlyOneError::MoreThanOne(first, second)),
+        }
+    }
+
+    /// Fold the items with `f` until it returns [`ControlFlow::Break`], returning the accumulated
+    /// value (either the value carried by `Break`, or the final value if the iterator is
+    /// exhausted).
+    ///
+    /// The remaining items after the break point are not consumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    /// use std::ops::ControlFlow;
+    ///
+    /// // Sum until the total would exceed 10.
+    /// let sum = (1..).fold_while(0, |sum, n| match sum + n {
+    ///     total if total > 10 => ControlFlow::Break(sum),
+    ///     total => ControlFlow::Continue(total),
+    /// });
+    /// assert_eq!(sum, 10);
+    /// ```
+    fn fold_while<B, F>(mut self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> ControlFlow<B, B>,
+    {
+        match self.try_fold(init, f) {
+            ControlFlow::Continue(acc) | ControlFlow::Break(acc) => acc,
+        }
+    }
+
+    /// Sum the values projected from each item by `f`.
+    ///
+    /// This is a shorthand for `map(f).sum()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// let total: usize = ["a", "bb", "ccc"].into_iter().sum_by(str::len);
+    /// assert_eq!(total, 6);
+    /// ```
+    fn sum_by<N, F>(self, f: F) -> N
+    where
+        N: Sum,
+        F: FnMut(Self::Item) -> N,
+    {
+        self.map(f).sum()
+    }
+
+    /// Returns the element that gives the maximum value from the specified function, or `None` if
+    /// the iterator is empty.
+    ///
+    /// If several elements are equally maximum, the **first** element is returned. This is in
+    /// contrast to [`Iterator::max_by_key`], which returns the **last** one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// let words = ["apple", "kiwi", "mango", "fig"];
+    /// assert_eq!(words.into_iter().max_by_key_first(|w| w.len()), Some("apple"));
+    /// assert_eq!(words.into_iter().max_by_key(|w| w.len()), Some("mango"));
+    /// ```
+    fn max_by_key_first<K, F>(self, mut f: F) -> Option<Self::Item>
+    where
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let mut max: Option<(K, Self::Item)> = None;
+
+        for item in self {
+            let key = f(&item);
+
+            if max.as_ref().is_none_or(|(max_key, _)| key > *max_key) {
+                max = Some((key, item));
+            }
+        }
+
+        max.map(|(_, item)| item)
+    }
+
+    /// Construct a [`GroupConsecutiveBy`] iterator that yields each run of consecutive items
+    /// sharing the same key, along with the key.
+    ///
+    /// Each group is yielded lazily, as soon as its end is reached. Items with the same key that
+    /// are not consecutive end up in different groups.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// let groups: Vec<_> = [1, 3, 2, 4, 5].into_iter().group_consecutive_by(|n| n % 2).collect();
+    /// assert_eq!(groups, [(1, vec![1, 3]), (0, vec![2, 4]), (1, vec![5])]);
+    /// ```
+    fn group_consecutive_by<K, F>(self, key: F) -> GroupConsecutiveBy<Self, K, F>
+    where
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        GroupConsecutiveBy {
+            iter: self,
+            key,
+            pending: None,
+        }
+    }
+
+    /// Construct an [`Intersperse`] iterator that yields a clone of `sep` between adjacent items
+    /// lazily.
+    ///
+    /// This mirrors the still unstable [`Iterator::intersperse`] for stable Rust. Because of the
+    /// same name, calling this method with the method call syntax triggers the
+    /// `unstable_name_collisions` lint. Call it as `IterExt::intersperse(iter, sep)` to avoid the
+    /// warning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// let s: String = IterExt::intersperse(["a", "b", "c"].into_iter(), ", ").collect();
+    /// assert_eq!(s, "a, b, c");
+    /// ```
+    fn intersperse(self, sep: Self::Item) -> Intersperse<Self>
+    where
+        Self::Item: Clone,
+    {
+        Intersperse {
+            iter: self.peekable(),
+            sep,
+            needs_sep: false,
+        }
+    }
+
+    /// Collect the items into a [`Vec`].
+    ///
+    /// This is a turbofish-free shorthand for `collect::<Vec<_>>()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// assert_eq!((1..=3).map(|n| n * 2).collect_vec(), [2, 4, 6]);
+    /// ```
+    fn collect_vec(self) -> Vec<Self::Item> {
+        self.collect()
+    }
+
+    /// Returns the indices of all the items matching `pred`.
+    ///
+    /// This is the counterpart of [`Iterator::position`] that finds every match instead of only
+    /// the first one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// assert_eq!("banana".chars().positions(|c| *c == 'a'), [1, 3, 5]);
+    /// ```
+    fn positions<F>(self, mut pred: F) -> Vec<usize>
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.enumerate()
+            .filter_map(|(i, item)| pred(&item).then_some(i))
+            .collect()
+    }
+
+    /// Fold the items with `f` like [`Iterator::fold`], but collect every intermediate state of
+    /// the accumulator (a prefix scan), such as running sums.
+    ///
+    /// The initial value itself is not included, so the result has as many elements as the
+    /// iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// assert_eq!([1, 2, 3].into_iter().running_fold(0, |sum, n| sum + n), [1, 3, 6]);
+    /// ```
+    fn running_fold<B, F>(self, init: B, mut f: F) -> Vec<B>
+    where
+        B: Clone,
+        F: FnMut(&B, Self::Item) -> B,
+    {
+        self.scan(init, |acc, item| {
+            *acc = f(acc, item);
+            Some(acc.clone())
+        })
+        .collect()
+    }
+
+    /// Returns the last `n` items (in their original order) in a single pass.
+    ///
+    /// Only up to `n` items are buffered at a time (in a [`VecDeque`] ring buffer), so this works
+    /// on iterators of unknown length. If the iterator has fewer than `n` items, all of them are
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// assert_eq!((1..=10).take_last(3), [8, 9, 10]);
+    /// assert_eq!((1..=2).take_last(3), [1, 2]);
+    /// ```
+    fn take_last(self, n: usize) -> Vec<Self::Item> {
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let mut last = VecDeque::new();
+
+        for item in self {
+            if last.len() == n {
+                last.pop_front();
+            }
+
+            last.push_back(item);
+        }
+
+        last.into()
+    }
+
+    /// Returns the last item matching `pred`, or `None` if there is no match.
+    ///
+    /// This scans the whole iterator and keeps the most recent match, so it works on iterators
+    /// that are not [`DoubleEndedIterator`] without collecting and reversing them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// assert_eq!((1..=10).last_where(|n| n % 3 == 0), Some(9));
+    /// ```
+    fn last_where<F>(self, mut pred: F) -> Option<Self::Item>
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.filter(|item| pred(item)).last()
+    }
+
+    /// Check whether all the items are equal to each other.
+    ///
+    /// Returns true for an empty or single-element iterator. Stops at the first item that differs
+    /// from the first one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// assert!([1, 1, 1].into_iter().all_equal());
+    /// assert!(![1, 2, 1].into_iter().all_equal());
+    /// ```
+    fn all_equal(mut self) -> bool
+    where
+        Self::Item: PartialEq,
+    {
+        match self.next() {
+            None => true,
+            Some(first) => self.all(|item| item == first),
+        }
+    }
+
+    /// Collect an iterator of key-value pairs into a [`HashMap`].
+    ///
+    /// This is a turbofish-free shorthand for `collect::<HashMap<_, _>>()`. If a key appears more
+    /// than once, the last value wins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// let map = [("a", 1), ("b", 2), ("a", 3)].into_iter().collect_map();
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map["a"], 3);
+    /// ```
+    fn collect_map<K, V>(self) -> HashMap<K, V>
+    where
+        Self: Iterator<Item = (K, V)>,
+        K: Hash + Eq,
+    {
+        self.collect()
+    }
+
+    /// Construct a [`Windowed`] iterator that yields overlapping windows of `size` consecutive
+    /// items as [`Vec`]s.
+    ///
+    /// This is the counterpart of [`slice::windows`] for arbitrary iterators. The windows stop
+    /// when fewer than `size` items remain, so nothing is yielded if the iterator has fewer than
+    /// `size` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// let windows: Vec<_> = (1..=4).windowed(2).collect();
+    /// assert_eq!(windows, [vec![1, 2], vec![2, 3], vec![3, 4]]);
+    /// ```
+    fn windowed(self, size: usize) -> Windowed<Self>
+    where
+        Self::Item: Clone,
+    {
+        assert!(size != 0, "windowed: window size must be non-zero");
+
+        Windowed {
+            iter: self,
+            window: VecDeque::new(),
+            size,
+        }
+    }
+
+    /// Skip the first `start` items, then yield every `step`-th item (starting with the first one
+    /// after the skipped items).
+    ///
+    /// This is a shorthand for `skip(start).step_by(step)` that states the intent more clearly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is 0 (same as [`Iterator::step_by`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// let odds: Vec<_> = (0..10).step_by_from(1, 2).collect();
+    /// assert_eq!(odds, [1, 3, 5, 7, 9]);
+    /// ```
+    fn step_by_from(self, start: usize, step: usize) -> StepBy<Skip<Self>> {
+        self.skip(start).step_by(step)
+    }
+
+    /// Construct a [`CartesianProduct`] iterator that yields all the pairs of an item of this
+    /// iterator and an item of `other`.
+    ///
+    /// The pairs are yielded in lexicographic order, that is, `other` is iterated completely (by
+    /// cloning it) for each item of this iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    ///
+    /// let pairs: Vec<_> = [1, 2].into_iter().cartesian_product(['a', 'b']).collect();
+    /// assert_eq!(pairs, [(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+    /// ```
+    fn cartesian_product<J>(self, other: J) -> CartesianProduct<Self, J::IntoIter>
+    where
+        Self::Item: Clone,
+        J: IntoIterator,
+        J::IntoIter: Clone,
+    {
+        let other = other.into_iter();
+
+        CartesianProduct {
+            iter: self,
+            item: None,
+            other: other.clone(),
+            other_orig: other,
+        }
+    }
+
+    /// Construct a [`CountInto`] iterator that increments `counter` for each item yielded.
+    ///
+    /// This is useful for lightweight throughput instrumentation of a pipeline, since the
+    /// counter can be read from other threads while the items are flowing. The counter is
+    /// incremented with [`Ordering::Relaxed`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use est::iter::IterExt;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// let counter = AtomicUsize::new(0);
+    /// let evens = (1..=10).count_into(&counter).filter(|n| n % 2 == 0).count();
+    /// assert_eq!(evens, 5);
+    /// assert_eq!(counter.load(Ordering::Relaxed), 10);
+    /// ```
+    fn count_into(self, counter: &AtomicUsize) -> CountInto<'_, Self> {
+        CountInto {
+            iter: self,
+            counter,
+        }
+    }
+}
+
+impl<T: Iterator + Sized> IterExt for T {}
+
+/// A [`Stream`] that groups the items received from a [`Receiver`] into batches (requires the
+/// `stream` feature flag).
+///
+/// Use [`batch_with_timeout`] to construct.
+#[cfg(feature = "stream")]
+#[derive(Debug)]
+pub struct BatchWithTimeout<T> {
+    rx: Receiver<T>,
+    max: usize,
+    window: Duration,
+    batch: Vec<T>,
+    deadline: Pin<Box<Sleep>>,
+}
+
+#[cfg(feature = "stream")]
+impl<T> BatchWithTimeout<T> {
+    /// Receive the next batch.
+    ///
+    /// This is a shorthand for awaiting the next item of the [`Stream`], so that no stream
+    /// combinator crate is needed.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. The items collected into the pending batch are kept, and
+    /// the batch is yielded by the next call.
+    pub async fn recv(&mut self) -> Option<Vec<T>> {
+        poll_fn(|cx| Pin::new(&mut *self).poll_next(cx)).await
+    }
+
+    /// Consume `self`, returning the underlying [`Receiver`].
+    ///
+    /// The items collected into the pending batch, if any, are dropped.
+    pub fn into_inner(self) -> Receiver<T> {
+        self.rx
+    }
+
+    fn flush(&mut self) -> Option<Vec<T>> {
+        Some(std::mem::replace(
+            &mut self.batch,
+            Vec::with_capacity(self.max),
+        ))
+    }
+}
+
+// No field is ever pinned structurally (the timer is boxed), so this is sound for any `T`.
+#[cfg(feature = "stream")]
+impl<T> Unpin for BatchWithTimeout<T> {}
+
+#[cfg(feature = "stream")]
+impl<T> Stream for BatchWithTimeout<T> {
+    type Item = Vec<T>;
+
+    /// Waits for the first item of the batch, and then collects more items until the batch has
+    /// `max` items, `window` has elapsed since the first item, or the channel is closed. Yields
+    /// `None` once the channel is closed and all the items have been received.
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        while this.batch.len() < this.max {
+            match this.rx.poll_recv(cx) {
+                Poll::Ready(Some(item)) => {
+                    if this.batch.is_empty() {
+                        this.deadline.as_mut().reset(Instant::now() + this.window);
+                    }
+
+                    this.batch.push(item);
+                }
+                Poll::Ready(None) if this.batch.is_empty() => return Poll::Ready(None),
+                Poll::Ready(None) => break,
+                Poll::Pending if this.batch.is_empty() => return Poll::Pending,
+                Poll::Pending => match this.deadline.as_mut().poll(cx) {
+                    Poll::Ready(()) => break,
+                    Poll::Pending => return Poll::Pending,
+                },
+            }
+        }
+
+        Poll::Ready(this.flush())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::from(!self.batch.is_empty()), None)
+    }
+}
+
+/// Group the items received from `rx` into batches of up to `max` items, flushing a batch early
+/// when `window` elapses since its first item (requires the `stream` feature flag).
+///
+/// This is a common pattern for batching downstream writes. The returned [`BatchWithTimeout`]
+/// implements [`Stream`], so it can be used with stream combinators, or polled directly with
+/// [`BatchWithTimeout::recv`].
+///
+/// # Panics
+///
+/// Panics if `max` is 0.
+///
+/// # Example
+///
+/// ```
+/// use est::iter::batch_with_timeout;
+/// use std::time::Duration;
+/// use tokio::sync::mpsc;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let (tx, rx) = mpsc::channel(16);
+///     let mut batches = batch_with_timeout(rx, 2, Duration::from_millis(100));
+///
+///     for n in 0..3 {
+///         tx.send(n).await.unwrap();
+///     }
+///     drop(tx);
+///
+///     assert_eq!(batches.recv().await, Some(vec![0, 1]));
+///     assert_eq!(batches.recv().await, Some(vec![2]));
+///     assert_eq!(batches.recv().await, None);
+/// }
+/// ```
+#[cfg(feature = "stream")]
+pub fn batch_with_timeout<T>(rx: Receiver<T>, max: usize, window: Duration) -> BatchWithTimeout<T> {
+    assert!(
+        max != 0,
+        "batch_with_timeout: max batch size must be non-zero"
+    );
+
+    BatchWithTimeout {
+        rx,
+        max,
+        window,
+        batch: Vec::with_capacity(max),
+        deadline: Box::pin(sleep_until(Instant::now())),
+    }
+}
+
+/// Run the `Future` created by `f` for each item, with at most `limit` of them running
+/// concurrently, and resolve once all of them have completed.
+///
+/// The `Future`s are polled concurrently within the task that awaits this function (nothing is
+/// spawned), so neither `Send` nor `'static` is required. As soon as one of them completes, a new
+/// one is created from the next item.
+///
+/// # Panics
+///
+/// Panics if `limit` is 0.
+///
+/// # Example
+///
+/// ```
+/// use est::iter::for_each_concurrent;
+/// use std::sync::Mutex;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let results = Mutex::new(Vec::new());
+///     for_each_concurrent(1..=4, 2, async |n| results.lock().unwrap().push(n * 2)).await;
+///
+///     let mut results = results.into_inner().unwrap();
+///     results.sort();
+///     assert_eq!(results, [2, 4, 6, 8]);
+/// }
+/// ```
+pub async fn for_each_concurrent<I, F, Fut>(iter: I, limit: usize, mut f: F)
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    assert!(limit != 0, "for_each_concurrent: limit must be non-zero");
+
+    let mut iter = iter.into_iter().fuse();
+    let mut running: Vec<Pin<Box<Fut>>> = Vec::with_capacity(limit);
+
+    poll_fn(|cx| {
+        loop {
+            while running.len() < limit {
+                match iter.next() {
+                    Some(item) => running.push(Box::pin(f(item))),
+                    None => break,
+                }
+            }
+
+            if running.is_empty() {
+                return Poll::Ready(());
+            }
+
+            let len = running.len();
+            running.retain_mut(|future| future.as_mut().poll(cx).is_pending());
+
+            // Nothing completed, wait to be woken up.
+            if running.len() == len {
+                return Poll::Pending;
+            }
+        }
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts() {
+        let counts = "the quick fox and the lazy dog and the cat"
+            .split_whitespace()
+            .counts();
+        assert_eq!(counts.len(), 7);
+        assert_eq!(counts["the"], 3);
+        assert_eq!(counts["and"], 2);
+        assert_eq!(counts["fox"], 1);
+        assert!(!counts.contains_key("bird"));
+
+        assert!(std::iter::empty::<i32>().counts().is_empty());
+    }
+
+    #[test]
+    fn try_collect() {
+        let nums: Result<Vec<i32>, &str> = IterExt::try_collect([Ok(1), Ok(2), Ok(3)].into_iter());
+        assert_eq!(nums, Ok(vec![1, 2, 3]));
+
+        let mut visited = 0;
+        let nums: Result<Vec<i32>, &str> = IterExt::try_collect(
+            [Ok(1), Err("first"), Ok(3), Err("second")]
+                .into_iter()
+                .inspect(|_| visited += 1),
+        );
+        assert_eq!(nums, Err("first"));
+        assert_eq!(visited, 2);
+    }
+
+    #[test]
+    fn minmax() {
+        assert_eq!(std::iter::empty::<i32>().minmax(), None);
+        assert_eq!([7].into_iter().minmax(), Some((7, 7)));
+        assert_eq!([3, 1, 4, 1, 5, 9, 2, 6].into_iter().minmax(), Some((1, 9)));
+        assert_eq!([5, 4, 3, 2, 1].into_iter().minmax(), Some((1, 5)));
+
+        // Ties: the first minimum and the last maximum.
+        let items = [(1, 'a'), (0, 'b'), (2, 'c'), (0, 'd'), (2, 'e')];
+        let minmax = items
+            .into_iter()
+            .map(|(key, tag)| Tagged(key, tag))
+            .minmax()
+            .unwrap();
+        assert_eq!((minmax.0.1, minmax.1.1), ('b', 'e'));
+    }
+
+    #[test]
+    fn dedup_count() {
+        let runs: Vec<_> = ['a', 'a', 'b', 'a'].into_iter().dedup_count().collect();
+        assert_eq!(runs, [('a', 2), ('b', 1), ('a', 1)]);
+
+        let runs: Vec<_> = [1, 1, 1].into_iter().dedup_count().collect();
+        assert_eq!(runs, [(1, 3)]);
+
+        assert_eq!(std::iter::empty::<i32>().dedup_count().next(), None);
+    }
+
+    #[test]
+    fn chunks_owned() {
+        let chunks: Vec<_> = (1..=6).chunks_owned(3).collect();
+        assert_eq!(chunks, [vec![1, 2, 3], vec![4, 5, 6]]);
+
+        let chunks: Vec<_> = (1..=7).chunks_owned(3).collect();
+        assert_eq!(chunks, [vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+
+        let chunks: Vec<_> = (1..=2).chunks_owned(3).collect();
+        assert_eq!(chunks, [vec![1, 2]]);
+
+        // Items do not have to be `Clone`.
+        struct NotClone(i32);
+        let chunks: Vec<_> = (0..4).map(NotClone).chunks_owned(2).collect();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[1][1].0, 3);
+
+        assert_eq!(std::iter::empty::<i32>().chunks_owned(3).next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn chunks_owned_zero() {
+        let _ = (1..=3).chunks_owned(0);
+    }
+
+    #[test]
+    fn join_to_string() {
+        assert_eq!(std::iter::empty::<i32>().join_to_string(", "), "");
+        assert_eq!(["alone"].iter().join_to_string(", "), "alone");
+        assert_eq!([1, 2, 3].iter().join_to_string(", "), "1, 2, 3");
+        assert_eq!((1..=4).join_to_string(""), "1234");
+        assert_eq!([1.5, -2.0].iter().join_to_string(" | "), "1.5 | -2");
+        assert_eq!("abc".chars().join_to_string("-"), "a-b-c");
+    }
+
+    #[test]
+    fn find_duplicate() {
+        let mut iter = [3, 1, 4, 1, 5, 9, 2, 6].into_iter();
+        assert_eq!(iter.by_ref().find_duplicate(), Some(1));
+        assert_eq!(iter.next(), Some(5));
+
+        assert_eq!("abcdefg".chars().find_duplicate(), None);
+        assert_eq!("hello".chars().find_duplicate(), Some('l'));
+        assert_eq!(std::iter::empty::<i32>().find_duplicate(), None);
+    }
+
+    #[test]
+    fn unique() {
+        let unique: Vec<_> = "mississippi".chars().unique().collect();
+        assert_eq!(unique, ['m', 'i', 's', 'p']);
+
+        let mut unique = [1, 1, 2, 1, 3].into_iter().unique();
+        assert_eq!(unique.next(), Some(1));
+        assert_eq!(unique.next(), Some(2));
+        assert_eq!(unique.next(), Some(3));
+        assert_eq!(unique.next(), None);
+
+        assert_eq!(std::iter::empty::<i32>().unique().next(), None);
+    }
+
+    #[test]
+    fn partition_map() {
+        let (evens, odds): (Vec<_>, Vec<_>) = (1..=6).partition_map(|n| match n % 2 {
+            0 => Either::Left(n / 2),
+            _ => Either::Right(n.to_string()),
+        });
+        assert_eq!(evens, [1, 2, 3]);
+        assert_eq!(odds, ["1", "3", "5"]);
+
+        let (left, right): (Vec<i32>, Vec<i32>) =
+            std::iter::empty::<i32>().partition_map(Either::Left);
+        assert!(left.is_empty() && right.is_empty());
+    }
+
+    #[test]
+    fn exactly_one() {
+        assert_eq!(
+            std::iter::empty::<i32>().exactly_one(),
+            Err(ExactlyOneError::Empty)
+        );
+        assert_eq!(["row"].into_iter().exactly_one(), Ok("row"));
+
+        let mut iter = [1, 2, 3].into_iter();
+        assert_eq!(
+            iter.by_ref().exactly_one(),
+            Err(ExactlyOneError::MoreThanOne(1, 2))
+        );
+        assert_eq!(iter.next(), Some(3));
+
+        let err = (1..).exactly_one().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "exactly_one: the iterator has more than one element"
+        );
+    }
+
+    #[test]
+    fn fold_while() {
+        let mut visited = Vec::new();
+        let mut iter = [1, 2, 3, -1, 4].into_iter();
+        let sum = iter.by_ref().fold_while(0, |sum, n| {
+            visited.push(n);
+            match n {
+                n if n < 0 => ControlFlow::Break(sum),
+                n => ControlFlow::Continue(sum + n),
+            }
+        });
+        assert_eq!(sum, 6);
+        assert_eq!(visited, [1, 2, 3, -1]);
+        assert_eq!(iter.next(), Some(4));
+
+        let sum = (1..=4).fold_while(0, |sum, n| ControlFlow::Continue(sum + n));
+        assert_eq!(sum, 10);
+
+        let product = std::iter::empty::<i32>().fold_while(1, |p, n| ControlFlow::Break(p * n));
+        assert_eq!(product, 1);
+    }
+
+    #[test]
+    fn sum_by() {
+        struct Item {
+            price: u32,
+            weight: f64,
+        }
+
+        let items = [
+            Item {
+                price: 3,
+                weight: 0.5,
+            },
+            Item {
+                price: 4,
+                weight: 1.25,
+            },
+        ];
+        assert_eq!(items.iter().sum_by(|item| item.price), 7);
+        assert_eq!(items.iter().sum_by(|item| item.weight), 1.75);
+        assert_eq!(std::iter::empty::<Item>().sum_by(|item| item.price), 0);
+    }
+
+    #[test]
+    fn max_by_key_first() {
+        let items = [(1, 'a'), (3, 'b'), (2, 'c'), (3, 'd')];
+        assert_eq!(
+            items.into_iter().max_by_key_first(|item| item.0),
+            Some((3, 'b'))
+        );
+        assert_eq!(items.into_iter().max_by_key(|item| item.0), Some((3, 'd')));
+
+        assert_eq!([5].into_iter().max_by_key_first(|n| *n), Some(5));
+        assert_eq!(std::iter::empty::<i32>().max_by_key_first(|n| *n), None);
+    }
+
+    #[test]
+    fn group_consecutive_by() {
+        let groups: Vec<_> = [2, 4, 1, 3, 5, 6, 8, 7]
+            .into_iter()
+            .group_consecutive_by(|n| n % 2 == 0)
+            .collect();
+        assert_eq!(
+            groups,
+            [
+                (true, vec![2, 4]),
+                (false, vec![1, 3, 5]),
+                (true, vec![6, 8]),
+                (false, vec![7])
+            ]
+        );
+
+        let groups: Vec<_> = "aAbB"
+            .chars()
+            .group_consecutive_by(|c| c.to_ascii_lowercase())
+            .collect();
+        assert_eq!(groups, [('a', vec!['a', 'A']), ('b', vec!['b', 'B'])]);
+
+        assert_eq!(
+            std::iter::empty::<i32>()
+                .group_consecutive_by(|n| *n)
+                .next(),
+            None
+        );
+    }
+
+    #[test]
+    fn intersperse() {
+        let empty: Vec<_> = IterExt::intersperse(std::iter::empty::<i32>(), 0).collect();
+        assert!(empty.is_empty());
+
+        let single: Vec<_> = IterExt::intersperse([1].into_iter(), 0).collect();
+        assert_eq!(single, [1]);
+
+        let multi: Vec<_> = IterExt::intersperse([1, 2, 3].into_iter(), 0).collect();
+        assert_eq!(multi, [1, 0, 2, 0, 3]);
+    }
+
+    #[test]
+    fn collect_vec() {
+        let iter = "a1b2c3".chars().filter(char::is_ascii_digit);
+        assert_eq!(iter.clone().collect_vec(), iter.collect::<Vec<_>>());
+        assert_eq!(std::iter::empty::<i32>().collect_vec(), Vec::<i32>::new());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn for_each_concurrent() {
+        use std::{
+            sync::atomic::{AtomicUsize, Ordering},
+            time::Duration,
+        };
+
+        let current = AtomicUsize::new(0);
+        let max = AtomicUsize::new(0);
+        let done = AtomicUsize::new(0);
+        super::for_each_concurrent(0..10, 3, async |n| {
+            let now = current.fetch_add(1, Ordering::SeqCst) + 1;
+            max.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(10 * (n % 4 + 1))).await;
+            current.fetch_sub(1, Ordering::SeqCst);
+            done.fetch_add(1, Ordering::SeqCst);
+        })
+        .await;
+        assert_eq!(max.load(Ordering::SeqCst), 3);
+        assert_eq!(done.load(Ordering::SeqCst), 10);
+
+        super::for_each_concurrent(std::iter::empty::<i32>(), 1, async |_| unreachable!()).await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn for_each_concurrent_zero() {
+        super::for_each_concurrent(0..1, 0, async |_| {}).await;
+    }
+
+    #[test]
+    fn positions() {
+        assert_eq!(
+            [1, 4, 2, 8, 5, 7].into_iter().positions(|n| n % 2 == 0),
+            [1, 2, 3]
+        );
+        assert!([1, 3, 5].into_iter().positions(|n| n % 2 == 0).is_empty());
+        assert!(std::iter::empty::<i32>().positions(|_| true).is_empty());
+    }
+
+    #[test]
+    fn running_fold() {
+        assert_eq!(
+            [1, 2, 3].into_iter().running_fold(0, |sum, n| sum + n),
+            [1, 3, 6]
+        );
+        assert_eq!(
+            (1..=4).running_fold(1, |product, n| product * n),
+            [1, 2, 6, 24]
+        );
+        assert_eq!(
+            "abc"
+                .chars()
+                .running_fold(String::new(), |s, c| format!("{s}{c}")),
+            ["a", "ab", "abc"]
+        );
+        assert!(
+            std::iter::empty::<i32>()
+                .running_fold(0, |sum, n| sum + n)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn take_last() {
+        assert_eq!((1..=10).take_last(3), [8, 9, 10]);
+        assert_eq!((1..=3).take_last(3), [1, 2, 3]);
+        assert_eq!((1..=2).take_last(5), [1, 2]);
+        assert!((1..=10).take_last(0).is_empty());
+        assert!(std::iter::empty::<i32>().take_last(3).is_empty());
+    }
+
+    #[test]
+    fn last_where() {
+        let words = ["apple", "bob", "avocado", "cat", "axe", "dog"];
+        assert_eq!(
+            words.into_iter().last_where(|w| w.starts_with('a')),
+            Some("axe")
+        );
+        assert_eq!(words.into_iter().last_where(|w| w.is_empty()), None);
+        assert_eq!(std::iter::empty::<i32>().last_where(|_| true), None);
+    }
+
+    #[test]
+    fn all_equal() {
+        assert!("aaaa".chars().all_equal());
+        assert!(!"aaba".chars().all_equal());
+        assert!([42].into_iter().all_equal());
+        assert!(std::iter::empty::<i32>().all_equal());
+    }
+
+    #[test]
+    fn collect_map() {
+        let map = ["x", "yy", "zzz"]
+            .into_iter()
+            .map(|s| (s, s.len()))
+            .collect_map();
+        assert_eq!(map, HashMap::from([("x", 1), ("yy", 2), ("zzz", 3)]));
+
+        let map = [(1, 'a'), (2, 'b'), (1, 'c'), (2, 'd'), (1, 'e')]
+            .into_iter()
+            .collect_map();
+        assert_eq!(map, HashMap::from([(1, 'e'), (2, 'd')]));
+
+        assert!(std::iter::empty::<(i32, i32)>().collect_map().is_empty());
+    }
+
+    #[test]
+    fn windowed() {
+        let windows: Vec<_> = "abcde".chars().windowed(3).collect();
+        assert_eq!(
+            windows,
+            [
+                vec!['a', 'b', 'c'],
+                vec!['b', 'c', 'd'],
+                vec!['c', 'd', 'e']
+            ]
+        );
+
+        let windows: Vec<_> = (1..=3).windowed(1).collect();
+        assert_eq!(windows, [vec![1], vec![2], vec![3]]);
+
+        let windows: Vec<_> = (1..=3).windowed(3).collect();
+        assert_eq!(windows, [vec![1, 2, 3]]);
+
+        assert_eq!((1..=2).windowed(3).next(), None);
+        assert_eq!(std::iter::empty::<i32>().windowed(1).next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn windowed_zero() {
+        let _ = (1..=3).windowed(0);
+    }
+
+    #[test]
+    fn step_by_from() {
+        assert_eq!((0..10).step_by_from(0, 3).collect_vec(), [0, 3, 6, 9]);
+        assert_eq!((0..10).step_by_from(2, 3).collect_vec(), [2, 5, 8]);
+        assert_eq!((0..10).step_by_from(4, 1).collect_vec(), [4, 5, 6, 7, 8, 9]);
+        assert_eq!((0..10).step_by_from(9, 5).collect_vec(), [9]);
+        assert!((0..10).step_by_from(10, 2).collect_vec().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn step_by_from_zero() {
+        let _ = (0..10).step_by_from(1, 0);
+    }
+
+    #[test]
+    fn cartesian_product() {
+        let pairs = [1, 2]
+            .into_iter()
+            .cartesian_product(['a', 'b'])
+            .collect_vec();
+        assert_eq!(pairs, [(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+
+        let pairs = (0..3).cartesian_product(0..2).collect_vec();
+        assert_eq!(pairs.len(), 6);
+        assert_eq!(pairs[3], (1, 1));
+
+        assert_eq!((0..3).cartesian_product(0..0).next(), None);
+        assert_eq!((0..0).cartesian_product(0..3).next(), None);
+    }
+
+    #[test]
+    fn count_into() {
+        let counter = AtomicUsize::new(0);
+        let sum: i32 = (1..=4).count_into(&counter).sum();
+        assert_eq!(sum, 10);
+        assert_eq!(counter.load(Ordering::Relaxed), 4);
+
+        // Only the items actually yielded are counted, and the counter accumulates.
+        let mut iter = (1..=4).count_into(&counter);
+        iter.next();
+        assert_eq!(counter.load(Ordering::Relaxed), 5);
+        assert_eq!(iter.count(), 3);
+        assert_eq!(counter.load(Ordering::Relaxed), 8);
+    }
+
+    #[tokio::test(start_paused = true)]
+    #[cfg(feature = "stream")]
+    async fn batch_with_timeout() {
+        let (tx, rx) = tokio::sync::mpsc::channel(16);
+        let mut batches = super::batch_with_timeout(rx, 2, Duration::from_millis(100));
+
+        // Size-triggered flushes.
+        for n in 0..5 {
+            tx.send(n).await.unwrap();
+        }
+        assert_eq!(batches.recv().await, Some(vec![0, 1]));
+        assert_eq!(batches.recv().await, Some(vec![2, 3]));
+
+        // Time-triggered flush.
+        let start = Instant::now();
+        assert_eq!(batches.recv().await, Some(vec![4]));
+        assert_eq!(start.elapsed(), Duration::from_millis(100));
+
+        let sender = tx.clone();
+        tokio::spawn(async move {
+            sender.send(5).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            sender.send(6).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            sender.send(7).await.unwrap();
+        });
+        assert_eq!(batches.recv().await, Some(vec![5, 6]));
+        assert_eq!(batches.recv().await, Some(vec![7]));
+
+        // Flush on close, through stream combinators.
+        tx.send(8).await.unwrap();
+        tx.send(9).await.unwrap();
+        tx.send(10).await.unwrap();
+        drop(tx);
+        let rest: Vec<_> = tokio_stream::StreamExt::collect(batches).await;
+        assert_eq!(rest, [vec![8, 9], vec![10]]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    #[cfg(feature = "stream")]
+    async fn batch_with_timeout_cancel_safe() {
+        let (tx, rx) = tokio::sync::mpsc::channel(16);
+        let mut batches = super::batch_with_timeout(rx, 3, Duration::from_millis(100));
+
+        tx.send(0).await.unwrap();
+        tx.send(1).await.unwrap();
+        let cancelled = tokio::time::timeout(Duration::from_millis(10), batches.recv()).await;
+        assert!(cancelled.is_err());
+        assert_eq!(batches.size_hint(), (1, None));
+
+        // The pending batch survives the cancellation and keeps its deadline.
+        let start = Instant::now();
+        assert_eq!(batches.recv().await, Some(vec![0, 1]));
+        assert_eq!(start.elapsed(), Duration::from_millis(90));
+        assert_eq!(batches.size_hint(), (0, None));
+    }
+
+    #[derive(Debug, Clone)]
+    struct Tagged(i32, char);
+
+    impl PartialEq for Tagged {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Tagged {}
+
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+}
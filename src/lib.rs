@@ -7,17 +7,25 @@
 //!
 //! **The default feature will not enable anything** (based on the principle of minimum
 //! dependency). At the same time, each top-level module has a feature flag with the same name
-//! (currently including: `collections`, `future`, `process`, `result`, `slice`, `sync`, `task`, `thread`).
+//! (currently including: `collections`, `future`, `iter`, `process`, `result`, `slice`, `sync`, `task`, `thread`).
 //!
 //! There is also a feature flag called `full` that enables all features and introduces all
 //! optional dependencies.
 //!
 //! In addition, there are some optional feature flags as follows:
 //!
-//! - `signal`: Enables `ctrl-c` signal processing in the [`task::graceful`] module.
+//! - `signal`: Enables `ctrl-c` (and `SIGTERM` on Unix) signal processing in the
+//!   [`task::graceful`] module.
+//! - `stream`: Enables [`iter::batch_with_timeout`] (a [`futures_core::Stream`] of batches).
 //! - `task_tracker`: Enables the [`task::task_tracker`] module.
+//! - `cancellation_token`: Enables bridging the [`task::graceful`] module to
+//!   [`tokio_util::sync::CancellationToken`].
+//! - `tracing`: Names the tasks spawned by the [`task::graceful`] module in `tokio-console`
+//!   (requires building with `RUSTFLAGS="--cfg tokio_unstable"`).
 //! - `indexmap`: Implement [`collections::MapExt`] for [`indexmap::IndexMap`].
 //! - `serde`: Enables [`serde`] support for the entire crate.
+//! - `rand`: Enables [`slice::SliceExt::sample`] (based on [`rand`]).
+//! - `rayon`: Enables [`slice::SliceExt::par_has_dup`] (based on [`rayon`]).
 
 #[cfg(feature = "tokio")]
 pub use tokio;
@@ -28,6 +36,9 @@ pub mod collections;
 /// Extensions to the [`std::future`] module.
 #[cfg(feature = "future")]
 pub mod future;
+/// Extensions to the [`std::iter`] module.
+#[cfg(feature = "iter")]
+pub mod iter;
 /// Extensions to the [`std::process`] & [`tokio::process`] module.
 #[cfg(feature = "process")]
 pub mod process;
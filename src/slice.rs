@@ -1,5 +1,7 @@
-use std::collections::HashSet;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
+use std::ops::{Add, Range};
 
 /// Extensions to the [`slice`] type.
 pub trait SliceExt<T> {
@@ -43,128 +45,3238 @@ pub trait SliceExt<T> {
     fn has_dup(&self) -> bool
     where
         T: Hash + Eq;
+
+    /// Find the first duplicate element in the slice.
+    ///
+    /// This method scans the slice from left to right, and returns a reference to the first
+    /// element whose value has already been seen (that is, the *second* occurrence of the value),
+    /// or `None` if all elements are unique.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) average time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n) additional space to store the seen elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let slice = [1, 2, 3, 2, 1];
+    /// let dup = slice.find_dup().unwrap();
+    /// assert_eq!(*dup, 2);
+    /// assert!(std::ptr::eq(dup, &slice[3]));
+    ///
+    /// assert_eq!([1, 2, 3].find_dup(), None);
+    /// ```
+    ///
+    /// # Type Requirements
+    ///
+    /// The element type `T` must implement [`Hash`] and [`Eq`] traits to be used
+    /// in the internal [`HashSet`].
+    fn find_dup(&self) -> Option<&T>
+    where
+        T: Hash + Eq;
+
+    /// Find the indices of all duplicate elements in the slice.
+    ///
+    /// This method returns the indices of every element whose value has appeared earlier in the
+    /// slice, in ascending order. The first occurrence of a value is never reported, only the
+    /// subsequent ones.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) average time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n) additional space to store the seen elements. The returned `Vec` is
+    /// only allocated if there are duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert_eq!([1, 1, 2, 1].dup_indices(), [1, 3]);
+    /// assert!([1, 2, 3].dup_indices().is_empty());
+    /// ```
+    ///
+    /// # Type Requirements
+    ///
+    /// The element type `T` must implement [`Hash`] and [`Eq`] traits to be used
+    /// in the internal [`HashSet`].
+    fn dup_indices(&self) -> Vec<usize>
+    where
+        T: Hash + Eq;
+
+    /// Count the duplicate elements in the slice.
+    ///
+    /// This method returns how many elements are "extra" copies, that is, the length of the slice
+    /// minus the number of distinct values.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) average time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n) additional space to store the seen elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert_eq!([5, 5, 5].count_dups(), 2);
+    /// assert_eq!([1, 2, 1, 2].count_dups(), 2);
+    /// assert_eq!([1, 2, 3].count_dups(), 0);
+    /// ```
+    ///
+    /// # Type Requirements
+    ///
+    /// The element type `T` must implement [`Hash`] and [`Eq`] traits to be used
+    /// in the internal [`HashSet`].
+    fn count_dups(&self) -> usize
+    where
+        T: Hash + Eq;
+
+    /// Returns the distinct elements of the slice, in the order of their first appearance.
+    ///
+    /// Unlike `sort` + `dedup`, this method preserves the original order, and does not require
+    /// `T: Ord`.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) average time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n) additional space to store the seen elements and the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert_eq!([3, 1, 3, 2, 1].unique(), [3, 1, 2]);
+    /// ```
+    ///
+    /// # Type Requirements
+    ///
+    /// The element type `T` must implement [`Hash`] and [`Eq`] traits to be used
+    /// in the internal [`HashSet`], and [`Clone`] since the distinct elements are cloned into the
+    /// returned `Vec` (each only once).
+    fn unique(&self) -> Vec<T>
+    where
+        T: Hash + Eq + Clone;
+
+    /// Check if the slice contains elements with duplicate keys.
+    ///
+    /// This method applies `f` to each element, and returns `true` if any two elements have the
+    /// same key. This allows checking duplicates by a field of a struct, without requiring the
+    /// whole element to be [`Hash`] and [`Eq`].
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) average time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n) additional space to store the seen keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// struct Person {
+    ///     id: u32,
+    ///     name: &'static str,
+    /// }
+    ///
+    /// let people = [
+    ///     Person { id: 1, name: "Alice" },
+    ///     Person { id: 2, name: "Bob" },
+    ///     Person { id: 1, name: "Carol" },
+    /// ];
+    /// assert!(people.has_dup_by_key(|p| p.id));
+    /// assert!(!people.has_dup_by_key(|p| p.name));
+    /// ```
+    ///
+    /// # Type Requirements
+    ///
+    /// The key type `K` must implement [`Hash`] and [`Eq`] traits to be used
+    /// in the internal [`HashSet`].
+    fn has_dup_by_key<K, F>(&self, f: F) -> bool
+    where
+        K: Hash + Eq,
+        F: FnMut(&T) -> K;
+
+    /// Check if a **sorted** slice contains duplicate elements.
+    ///
+    /// This method compares adjacent elements, and returns `true` on the first equal pair. It
+    /// needs no heap allocation and no [`Hash`], but the result is only meaningful if the slice
+    /// is sorted (or at least, equal elements are adjacent). It is the caller's responsibility to
+    /// guarantee this; for unsorted slices, use [`has_dup`] instead.
+    ///
+    /// [`has_dup`]: SliceExt::has_dup
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(1) additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert!([1, 2, 2, 3].has_dup_sorted());
+    /// assert!(![1, 2, 3, 4].has_dup_sorted());
+    ///
+    /// // Unsorted input gives meaningless results.
+    /// assert!(![1, 2, 1].has_dup_sorted());
+    /// ```
+    fn has_dup_sorted(&self) -> bool
+    where
+        T: PartialEq;
+
+    /// Count the occurrences of each distinct element in the slice.
+    ///
+    /// This method returns a map from a reference to each distinct element to the number of times
+    /// it occurs, which is handy for building histograms.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) average time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n) additional space to store the counts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let freqs = ["a", "a", "b"].frequencies();
+    /// assert_eq!(freqs[&"a"], 2);
+    /// assert_eq!(freqs[&"b"], 1);
+    /// ```
+    ///
+    /// # Type Requirements
+    ///
+    /// The element type `T` must implement [`Hash`] and [`Eq`] traits to be used
+    /// as the key of the returned [`HashMap`].
+    fn frequencies(&self) -> HashMap<&T, usize>
+    where
+        T: Hash + Eq;
+
+    /// Find the most common element in the slice.
+    ///
+    /// This method returns a reference to the element with the highest occurrence count, or
+    /// `None` if the slice is empty. If several elements are equally common, the one that
+    /// appears first in the slice wins (the returned reference points to its first occurrence).
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) average time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n) additional space to store the counts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert_eq!([1, 2, 2, 3, 3].mode(), Some(&2));
+    /// assert_eq!([0; 0].mode(), None);
+    /// ```
+    ///
+    /// # Type Requirements
+    ///
+    /// The element type `T` must implement [`Hash`] and [`Eq`] traits to be used
+    /// in the internal [`HashMap`].
+    fn mode(&self) -> Option<&T>
+    where
+        T: Hash + Eq;
+
+    /// Find the minimum and maximum elements of the slice in a single traversal.
+    ///
+    /// This method returns references to the minimum and maximum elements, or `None` if the slice
+    /// is empty. If several elements are equally minimum, the first one is returned; if several
+    /// elements are equally maximum, the last one is returned (consistent with
+    /// [`Iterator::min`] and [`Iterator::max`]). For a single-element slice, both references
+    /// point to that element.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) time complexity, where n is the length of the slice. It uses the
+    /// pairwise comparison algorithm, which takes about 1.5n comparisons (instead of 2n when
+    /// finding the minimum and maximum separately).
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(1) additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert_eq!([3, 1, 4, 1, 5].min_max(), Some((&1, &5)));
+    /// assert_eq!([42].min_max(), Some((&42, &42)));
+    /// assert_eq!([0; 0].min_max(), None);
+    /// ```
+    fn min_max(&self) -> Option<(&T, &T)>
+    where
+        T: Ord;
+
+    /// Check if the slice is a permutation of `other`, that is, both slices contain the same
+    /// elements with the same multiplicities, regardless of order.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) average time complexity, where n is the length of the slice. Slices
+    /// of different lengths are rejected in O(1).
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n) additional space to store the counts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert!([1, 2, 2].is_permutation_of(&[2, 1, 2]));
+    /// assert!(![1, 2].is_permutation_of(&[1, 1]));
+    /// ```
+    ///
+    /// # Type Requirements
+    ///
+    /// The element type `T` must implement [`Hash`] and [`Eq`] traits to be used
+    /// in the internal [`HashMap`].
+    fn is_permutation_of(&self, other: &[T]) -> bool
+    where
+        T: Hash + Eq;
+
+    /// Returns the distinct elements present in both the slice and `other`, in the order of their
+    /// first appearance in the slice.
+    ///
+    /// Each shared element appears only once in the result, even if it is duplicated in either
+    /// slice.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n + m) average time complexity, where n and m are the lengths of the
+    /// slice and `other`.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n + m) additional space to store the elements of `other`, the seen
+    /// elements, and the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert_eq!([3, 1, 2, 1].intersect(&[1, 3, 5]), [3, 1]);
+    /// ```
+    ///
+    /// # Type Requirements
+    ///
+    /// The element type `T` must implement [`Hash`] and [`Eq`] traits to be used
+    /// in the internal [`HashSet`], and [`Clone`] since the shared elements are cloned into the
+    /// returned `Vec`.
+    fn intersect(&self, other: &[T]) -> Vec<T>
+    where
+        T: Hash + Eq + Clone;
+
+    /// Returns the distinct elements of the slice that are not present in `other`, in the order
+    /// of their first appearance in the slice.
+    ///
+    /// The result is deduplicated: each element appears only once, even if it is duplicated in
+    /// the slice.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n + m) average time complexity, where n and m are the lengths of the
+    /// slice and `other`.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n + m) additional space to store the elements of `other`, the seen
+    /// elements, and the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert_eq!([1, 2, 2, 3].difference(&[2]), [1, 3]);
+    /// ```
+    ///
+    /// # Type Requirements
+    ///
+    /// The element type `T` must implement [`Hash`] and [`Eq`] traits to be used
+    /// in the internal [`HashSet`], and [`Clone`] since the remaining elements are cloned into the
+    /// returned `Vec`.
+    fn difference(&self, other: &[T]) -> Vec<T>
+    where
+        T: Hash + Eq + Clone;
+
+    /// Split the slice into runs of consecutive elements that produce equal keys.
+    ///
+    /// The key function `f` is called once per element, and a new chunk starts whenever the key
+    /// of an element differs from the key of the previous one. The returned chunks borrow from
+    /// the slice, so no element is cloned. An empty slice yields no chunks.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(k) additional space, where k is the number of chunks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let chunks = [1, 1, 2, 2, 2, 1].chunk_by_key(|&x| x);
+    /// assert_eq!(chunks, [&[1, 1][..], &[2, 2, 2], &[1]]);
+    ///
+    /// let words = ["apple", "avocado", "banana", "cherry", "cranberry"];
+    /// let chunks = words.chunk_by_key(|w| w.chars().next());
+    /// assert_eq!(chunks.len(), 3);
+    /// ```
+    fn chunk_by_key<K, F>(&self, f: F) -> Vec<&[T]>
+    where
+        K: PartialEq,
+        F: FnMut(&T) -> K;
+
+    /// Return an iterator over each pair of adjacent elements of the slice.
+    ///
+    /// For a slice of length n, this yields `(&self[i], &self[i + 1])` for every `i` in
+    /// `0..n - 1`. It is equivalent to `windows(2)`, but yields tuples instead of sub-slices. The
+    /// iterator is empty if the slice has fewer than two elements.
+    ///
+    /// # Time Complexity
+    ///
+    /// Each call to `next` is O(1); iterating over all pairs is O(n).
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(1) additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let pairs: Vec<_> = [1, 2, 3].adjacent_pairs().collect();
+    /// assert_eq!(pairs, [(&1, &2), (&2, &3)]);
+    ///
+    /// let increasing = [1, 4, 9, 16].adjacent_pairs().all(|(a, b)| a < b);
+    /// assert!(increasing);
+    /// ```
+    fn adjacent_pairs<'a>(&'a self) -> impl Iterator<Item = (&'a T, &'a T)>
+    where
+        T: 'a;
+
+    /// Count how many elements of the slice are equal to `target`.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(1) additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert_eq!([1, 2, 1, 3, 1].count_occurrences(&1), 3);
+    /// assert_eq!(["a", "b"].count_occurrences(&"c"), 0);
+    /// ```
+    fn count_occurrences(&self, target: &T) -> usize
+    where
+        T: PartialEq;
+
+    /// Return the half-open range of indices of all elements equal to `target` in a sorted slice.
+    ///
+    /// The start of the range is the first index whose element is not less than `target`
+    /// (lower bound), and the end is the first index whose element is greater than `target`
+    /// (upper bound). If `target` is absent, the range is empty and its start is the position
+    /// where `target` could be inserted while keeping the slice sorted.
+    ///
+    /// The slice must be sorted in ascending order; otherwise the result is unspecified but the
+    /// method will not panic.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(log n) time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(1) additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let slice = [1, 2, 2, 2, 3, 5];
+    /// assert_eq!(slice.equal_range(&2), 1..4);
+    /// assert_eq!(slice.equal_range(&4), 5..5);
+    /// assert!(slice.equal_range(&4).is_empty());
+    /// ```
+    fn equal_range(&self, target: &T) -> Range<usize>
+    where
+        T: Ord;
+
+    /// Return the permutation of indices that would sort the slice.
+    ///
+    /// In the returned `Vec`, `result[k]` is the index of the k-th smallest element. The sort is
+    /// stable, so indices of equal elements keep their original relative order. This is useful
+    /// for reordering several parallel slices by the same key.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n log n) time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n) additional space for the returned indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let scores = [30, 10, 20, 10];
+    /// let order = scores.argsort();
+    /// assert_eq!(order, [1, 3, 2, 0]);
+    ///
+    /// let names = ["d", "a", "c", "b"];
+    /// let sorted: Vec<_> = order.iter().map(|&i| names[i]).collect();
+    /// assert_eq!(sorted, ["a", "b", "c", "d"]);
+    /// ```
+    fn argsort(&self) -> Vec<usize>
+    where
+        T: Ord;
+
+    /// Return the permutation of indices that would sort the slice by the key extracted with `f`.
+    ///
+    /// This is the same as [`argsort`](SliceExt::argsort), but compares elements by the key
+    /// returned from `f`. The sort is stable, so indices of elements with equal keys keep their
+    /// original relative order.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n log n) time complexity, where n is the length of the slice, calling
+    /// `f` O(n log n) times.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n) additional space for the returned indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let words = ["ccc", "a", "bb", "d"];
+    /// assert_eq!(words.argsort_by_key(|w| w.len()), [1, 3, 2, 0]);
+    /// ```
+    fn argsort_by_key<K, F>(&self, f: F) -> Vec<usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Find the starting index of the first contiguous occurrence of `needle` in the slice.
+    ///
+    /// Returns `None` if `needle` does not occur in the slice. An empty `needle` matches at the
+    /// start, so `Some(0)` is returned.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method uses a naive scan with O(n * m) worst-case time complexity, where n is the
+    /// length of the slice and m is the length of `needle`. For large inputs with many partial
+    /// matches, a dedicated substring search algorithm may be more appropriate.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(1) additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert_eq!([1, 1, 2].contains_subslice(&[1, 2]), Some(1));
+    /// assert_eq!([1, 2, 3].contains_subslice(&[2, 1]), None);
+    /// assert_eq!([1, 2, 3].contains_subslice(&[]), Some(0));
+    /// ```
+    fn contains_subslice(&self, needle: &[T]) -> Option<usize>
+    where
+        T: PartialEq;
+
+    /// Replace every element equal to `from` with a clone of `to`, in place.
+    ///
+    /// Returns the number of elements that were replaced. Elements not equal to `from` are left
+    /// untouched.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(1) additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let mut slice = [1, 0, 2, 0, 3];
+    /// assert_eq!(slice.replace_all(&0, 9), 2);
+    /// assert_eq!(slice, [1, 9, 2, 9, 3]);
+    /// ```
+    fn replace_all(&mut self, from: &T, to: T) -> usize
+    where
+        T: PartialEq + Clone;
+
+    /// Return the first index whose element's key, extracted with `f`, is not less than `key`.
+    ///
+    /// This is a key-based shortcut for [`partition_point`](slice::partition_point) with the
+    /// predicate `|item| f(item) < *key`. The slice must be sorted by the key extracted with
+    /// `f`; otherwise the result is unspecified. If every key is less than `key`, the length of
+    /// the slice is returned.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(log n) time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(1) additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let events = [(1, "boot"), (4, "login"), (4, "open"), (9, "logout")];
+    /// assert_eq!(events.partition_point_by_key(&4, |&(t, _)| t), 1);
+    /// assert_eq!(events.partition_point_by_key(&5, |&(t, _)| t), 3);
+    /// assert_eq!(events.partition_point_by_key(&10, |&(t, _)| t), 4);
+    /// ```
+    fn partition_point_by_key<K, F>(&self, key: &K, f: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Collapse runs of consecutive equal elements into `(value, run_length)` pairs.
+    ///
+    /// This is run-length encoding: each run is represented by a clone of its first element and
+    /// the number of elements in the run. Unlike [`frequencies`](SliceExt::frequencies), equal
+    /// elements that are not adjacent produce separate entries.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(k) additional space, where k is the number of runs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let encoded = ['a', 'a', 'b', 'a'].dedup_with_count();
+    /// assert_eq!(encoded, [('a', 2), ('b', 1), ('a', 1)]);
+    /// ```
+    fn dedup_with_count(&self) -> Vec<(T, usize)>
+    where
+        T: PartialEq + Clone;
+
+    /// Check if the slice is sorted in non-decreasing order by the key extracted with `f`.
+    ///
+    /// The check short-circuits on the first adjacent pair whose keys are out of order. Empty and
+    /// single-element slices are always sorted.
+    ///
+    /// This is the [`Ord`] counterpart of the inherent
+    /// [`is_sorted_by_key`](slice::is_sorted_by_key) of the standard library, which accepts any
+    /// [`PartialOrd`] key. It has a distinct name so that it is not shadowed by the inherent
+    /// method in method-call syntax.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) time complexity, where n is the length of the slice. `f` is called
+    /// up to twice per element.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(1) additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let words = ["a", "bb", "cc", "ddd"];
+    /// assert!(words.is_sorted_by_key_ord(|w| w.len()));
+    /// assert!(![3, 1, 2].is_sorted_by_key_ord(|&x| x));
+    /// ```
+    fn is_sorted_by_key_ord<K, F>(&self, f: F) -> bool
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Count the occurrences of each distinct element in a sorted slice.
+    ///
+    /// This method assumes the slice is sorted (or at least that equal elements are adjacent)
+    /// and returns each distinct element together with the length of its run, in slice order.
+    /// Unlike [`frequencies`](SliceExt::frequencies), it needs no hashing, so it works for
+    /// element types that only implement [`PartialEq`]. If equal elements are not adjacent, they
+    /// are reported as separate entries.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(k) additional space for the result, where k is the number of distinct
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let counts = [1, 1, 2, 3, 3, 3].group_counts_sorted();
+    /// assert_eq!(counts, [(&1, 2), (&2, 1), (&3, 3)]);
+    /// ```
+    fn group_counts_sorted(&self) -> Vec<(&T, usize)>
+    where
+        T: PartialEq;
+
+    /// Randomly choose up to `n` distinct elements of the slice using reservoir sampling.
+    ///
+    /// Each element has the same probability of being chosen, and no element is chosen twice.
+    /// If `n` is greater than or equal to the length of the slice, all elements are returned.
+    /// The order of the returned references is unspecified.
+    ///
+    /// This method is only available with the `rand` feature flag, which pulls in the [`rand`]
+    /// crate.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) time complexity, where n is the length of the slice, drawing one
+    /// random number per element after the first `n`. If `n` is 0 or not less than the length of
+    /// the slice, it returns immediately without drawing any random number.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(k) additional space for the result, where k is `n` or the length of
+    /// the slice, whichever is smaller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let slice = [1, 2, 3, 4, 5];
+    /// let sample = slice.sample(3, &mut rand::rng());
+    /// assert_eq!(sample.len(), 3);
+    /// assert!(!sample.has_dup());
+    ///
+    /// assert_eq!(slice.sample(10, &mut rand::rng()).len(), 5);
+    /// ```
+    #[cfg(feature = "rand")]
+    fn sample(&self, n: usize, rng: &mut impl rand::Rng) -> Vec<&T>;
+
+    /// Check if the slice contains duplicate elements, using multiple threads.
+    ///
+    /// The slice is split into one chunk per [`rayon`] worker thread. Each chunk is checked
+    /// into its own [`HashSet`] in parallel, and the sets are then merged pairwise, detecting
+    /// duplicates that span chunks. The result is always the same as [`has_dup`](SliceExt::has_dup).
+    ///
+    /// This method is only available with the `rayon` feature flag. Because of the overhead of
+    /// spawning work and merging sets, it is only faster than [`has_dup`](SliceExt::has_dup) for
+    /// large slices (typically well over tens of thousands of elements, depending on the cost of
+    /// hashing `T`); prefer the sequential version otherwise.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) average total work, where n is the length of the slice, spread
+    /// across the available threads.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n) additional space for the per-chunk [`HashSet`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let slice: Vec<_> = (0..100_000).collect();
+    /// assert!(!slice.par_has_dup());
+    ///
+    /// let slice: Vec<_> = (0..100_000).chain([42]).collect();
+    /// assert!(slice.par_has_dup());
+    /// ```
+    ///
+    /// # Type Requirements
+    ///
+    /// The element type `T` must implement [`Hash`] and [`Eq`] traits to be used
+    /// in the internal [`HashSet`], and [`Sync`] to be shared across threads.
+    #[cfg(feature = "rayon")]
+    fn par_has_dup(&self) -> bool
+    where
+        T: Hash + Eq + Sync;
+
+    /// Rotate the slice in place so that the first element equal to `target` moves to index 0.
+    ///
+    /// Returns `true` if `target` was found. If it was not found, the slice is left unchanged
+    /// and `false` is returned. See also [`rotate_left`](slice::rotate_left).
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(1) additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let mut slice = [3, 1, 2];
+    /// assert!(slice.rotate_to(&1));
+    /// assert_eq!(slice, [1, 2, 3]);
+    ///
+    /// assert!(!slice.rotate_to(&4));
+    /// assert_eq!(slice, [1, 2, 3]);
+    /// ```
+    fn rotate_to(&mut self, target: &T) -> bool
+    where
+        T: PartialEq;
+
+    /// Split the leading run of equal elements off the rest of the slice.
+    ///
+    /// Returns `(run, rest)`, where `run` is the longest non-empty prefix whose elements are all
+    /// equal to the first element, or `None` if the slice is empty.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(k) time complexity, where k is the length of the run.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(1) additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let slice = ['a', 'a', 'b', 'c'];
+    /// assert_eq!(slice.split_first_run(), Some((&['a', 'a'][..], &['b', 'c'][..])));
+    /// assert_eq!([0; 0].split_first_run(), None);
+    /// ```
+    fn split_first_run(&self) -> Option<(&[T], &[T])>
+    where
+        T: PartialEq;
+
+    /// Split the trailing run of equal elements off the rest of the slice.
+    ///
+    /// Returns `(rest, run)`, where `run` is the longest non-empty suffix whose elements are all
+    /// equal to the last element, or `None` if the slice is empty.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(k) time complexity, where k is the length of the run.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(1) additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let slice = ['a', 'b', 'c', 'c'];
+    /// assert_eq!(slice.split_last_run(), Some((&['a', 'b'][..], &['c', 'c'][..])));
+    /// assert_eq!([0; 0].split_last_run(), None);
+    /// ```
+    fn split_last_run(&self) -> Option<(&[T], &[T])>
+    where
+        T: PartialEq;
+
+    /// Return the index of the element with the maximum key extracted with `f`.
+    ///
+    /// If several elements have an equally maximum key, the index of the last one is returned,
+    /// consistent with [`Iterator::max_by_key`]. Returns `None` if the slice is empty.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(1) additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let words = ["bb", "a", "cc", "d"];
+    /// assert_eq!(words.position_max_by_key(|w| w.len()), Some(2));
+    /// ```
+    fn position_max_by_key<K, F>(&self, f: F) -> Option<usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Return the index of the element with the minimum key extracted with `f`.
+    ///
+    /// If several elements have an equally minimum key, the index of the first one is returned,
+    /// consistent with [`Iterator::min_by_key`]. Returns `None` if the slice is empty.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(1) additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let words = ["bb", "a", "cc", "d"];
+    /// assert_eq!(words.position_min_by_key(|w| w.len()), Some(1));
+    /// ```
+    fn position_min_by_key<K, F>(&self, f: F) -> Option<usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Interleave the elements of the slice with those of `other`.
+    ///
+    /// The result is `[self[0], other[0], self[1], other[1], ...]`. Once the shorter slice is
+    /// exhausted, the remaining elements of the longer one are appended in order. All elements
+    /// are cloned into the returned `Vec`.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n + m) time complexity, where n and m are the lengths of the slice and
+    /// `other`.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n + m) additional space for the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert_eq!([1, 3, 5].interleave(&[2, 4]), [1, 2, 3, 4, 5]);
+    /// assert_eq!([1].interleave(&[2, 3, 4]), [1, 2, 3, 4]);
+    /// ```
+    fn interleave(&self, other: &[T]) -> Vec<T>
+    where
+        T: Clone;
+
+    /// Return a clone of the median element of the slice, or `None` if the slice is empty.
+    ///
+    /// For odd-length slices this is the middle element in sorted order. For even-length slices
+    /// it is the lower of the two middle elements (no averaging is done, so this works for any
+    /// [`Ord`] type). The slice itself is not modified: its elements are cloned into a scratch
+    /// `Vec`, which is partially sorted with [`select_nth_unstable`](slice::select_nth_unstable).
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) average time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n) additional space for the scratch `Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert_eq!([5, 1, 3].median_cloned(), Some(3));
+    /// assert_eq!([4, 1, 3, 2].median_cloned(), Some(2));
+    /// assert_eq!([0; 0].median_cloned(), None);
+    /// ```
+    fn median_cloned(&self) -> Option<T>
+    where
+        T: Ord + Clone;
+
+    /// Check if all elements of the slice are equal to each other.
+    ///
+    /// Empty and single-element slices are vacuously all equal.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) time complexity, where n is the length of the slice. It stops at the
+    /// first element that differs from the first one.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(1) additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert!([7, 7, 7].all_equal());
+    /// assert!(![7, 8, 7].all_equal());
+    /// assert!([0; 0].all_equal());
+    /// ```
+    fn all_equal(&self) -> bool
+    where
+        T: PartialEq;
+
+    /// Find the indices of the first duplicate pair in the slice.
+    ///
+    /// This method scans the slice from left to right like [`find_dup`](SliceExt::find_dup), but
+    /// returns the indices `(i, j)` with `i < j` of both occurrences instead of a reference: `j`
+    /// is the index of the first element whose value has already been seen, and `i` is the
+    /// index where that value first appeared. Returns `None` if all elements are unique.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) average time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n) additional space to store the first index of each seen element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert_eq!(['a', 'b', 'a', 'b'].first_duplicate_pair(), Some((0, 2)));
+    /// assert_eq!([1, 2, 3].first_duplicate_pair(), None);
+    /// ```
+    ///
+    /// # Type Requirements
+    ///
+    /// The element type `T` must implement [`Hash`] and [`Eq`] traits to be used
+    /// in the internal [`HashMap`].
+    fn first_duplicate_pair(&self) -> Option<(usize, usize)>
+    where
+        T: Hash + Eq;
+
+    /// Call `f` on each overlapping mutable window of length `N`, from left to right.
+    ///
+    /// This is a mutable counterpart to [`windows`](slice::windows). Since overlapping mutable
+    /// windows cannot exist at the same time, the windows are visited one at a time: each call
+    /// to `f` sees the modifications made by the previous calls. If the slice is shorter than
+    /// `N`, `f` is never called.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method calls `f` n - N + 1 times, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(1) additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let mut slice = [1, 2, 3, 4];
+    /// slice.for_each_window_mut::<2, _>(|w| w[1] += w[0]);
+    /// assert_eq!(slice, [1, 3, 6, 10]);
+    /// ```
+    fn for_each_window_mut<const N: usize, F>(&mut self, f: F)
+    where
+        F: FnMut(&mut [T]);
+
+    /// Return the prefix sums of the slice.
+    ///
+    /// Element `i` of the returned `Vec` is the sum of `self[0..=i]`. The running accumulator is
+    /// cloned into the result at each step, so this is best suited to cheaply cloneable types
+    /// such as numbers. An empty slice yields an empty `Vec`.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n) additional space for the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let sums = [1, 2, 3, 4].prefix_sum();
+    /// assert_eq!(sums, [1, 3, 6, 10]);
+    ///
+    /// // Sum of `[2, 3]`, i.e. indices `1..3`.
+    /// assert_eq!(sums[2] - sums[0], 5);
+    /// ```
+    fn prefix_sum(&self) -> Vec<T>
+    where
+        T: Clone + Add<Output = T>;
+
+    /// Return the indices of all elements that satisfy `pred`, in ascending order.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) time complexity, where n is the length of the slice.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(k) additional space for the result, where k is the number of matching
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let slice = [1, 4, 7, 8, 10];
+    /// assert_eq!(slice.indices_where(|x| x % 2 == 0), [1, 3, 4]);
+    /// ```
+    fn indices_where<F>(&self, pred: F) -> Vec<usize>
+    where
+        F: FnMut(&T) -> bool;
+
+    /// Divide the slice into `parts` contiguous sub-slices whose lengths differ by at most one.
+    ///
+    /// Unlike [`chunks`](slice::chunks), which uses a fixed chunk size and may leave a small
+    /// last chunk, this method fixes the number of chunks. The remainder of the division is
+    /// distributed across the earliest chunks, so they are one element longer than the later
+    /// ones. If `parts` is greater than the length of the slice, the trailing chunks are empty.
+    /// If `parts` is zero, an empty `Vec` is returned.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(parts) time complexity.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(parts) additional space for the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let slice: Vec<_> = (0..10).collect();
+    /// let chunks = slice.chunks_balanced(3);
+    /// assert_eq!(chunks, [&[0, 1, 2, 3][..], &[4, 5, 6], &[7, 8, 9]]);
+    /// ```
+    fn chunks_balanced(&self, parts: usize) -> Vec<&[T]>;
+
+    /// Return up to `k` distinct elements with the highest number of occurrences, together with
+    /// their counts.
+    ///
+    /// The result is sorted by descending count. Elements with equal counts are ordered by
+    /// their first appearance in the slice. If `k` is greater than the number of distinct
+    /// elements, all of them are returned.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n + d log k) average time complexity, where n is the length of the
+    /// slice and d is the number of distinct elements.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(d) additional space for the internal [`HashMap`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let words = ["the", "cat", "the", "dog", "the", "cat"];
+    /// assert_eq!(words.most_common_n(2), [(&"the", 3), (&"cat", 2)]);
+    /// ```
+    ///
+    /// # Type Requirements
+    ///
+    /// The element type `T` must implement [`Hash`] and [`Eq`] traits to be used
+    /// in the internal [`HashMap`].
+    fn most_common_n(&self, k: usize) -> Vec<(&T, usize)>
+    where
+        T: Hash + Eq;
+
+    /// Split the slice between every pair of consecutive elements `a, b` for which `pred(a, b)`
+    /// returns `true`.
+    ///
+    /// The element `b` at each such boundary starts a new chunk. This generalizes
+    /// [`chunk_by_key`](SliceExt::chunk_by_key) to arbitrary adjacency rules. An empty slice
+    /// yields no chunks.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n) time complexity, where n is the length of the slice, calling `pred`
+    /// n - 1 times.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(k) additional space, where k is the number of chunks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// // Split into ascending runs.
+    /// let runs = [1, 2, 1, 3].split_when(|a, b| a > b);
+    /// assert_eq!(runs, [&[1, 2][..], &[1, 3]]);
+    /// ```
+    fn split_when<F>(&self, pred: F) -> Vec<&[T]>
+    where
+        F: FnMut(&T, &T) -> bool;
+
+    /// Combine the elements of the slice pairwise with those of `other` using `f`.
+    ///
+    /// Element `i` of the result is `f(&self[i], &other[i])`. If the slices have different
+    /// lengths, the result is truncated to the length of the shorter one, and the extra
+    /// elements of the longer slice are ignored.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(min(n, m)) time complexity, where n and m are the lengths of the slice
+    /// and `other`.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(min(n, m)) additional space for the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// let prices = [10, 20, 30];
+    /// let quantities = [3, 1];
+    /// assert_eq!(prices.zip_with(&quantities, |p, q| p * q), [30, 20]);
+    /// ```
+    fn zip_with<U, R, F>(&self, other: &[U], f: F) -> Vec<R>
+    where
+        F: FnMut(&T, &U) -> R;
+
+    /// Check if every element of the slice is also present in `other`, treating both slices as
+    /// sets.
+    ///
+    /// Order and multiplicity are ignored: `[1, 1, 1]` is a subset of `[1]`. An empty slice is
+    /// a subset of any slice.
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n + m) average time complexity, where n and m are the lengths of the
+    /// slice and `other`.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(m) additional space to store the elements of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert!([2, 1, 2].is_subset_of(&[1, 2, 3]));
+    /// assert!(![1, 4].is_subset_of(&[1, 2, 3]));
+    /// ```
+    ///
+    /// # Type Requirements
+    ///
+    /// The element type `T` must implement [`Hash`] and [`Eq`] traits to be used
+    /// in the internal [`HashSet`].
+    fn is_subset_of(&self, other: &[T]) -> bool
+    where
+        T: Hash + Eq;
+
+    /// Check if every element of `other` is also present in the slice, treating both slices as
+    /// sets.
+    ///
+    /// This is the same as `other.is_subset_of(self)`; see
+    /// [`is_subset_of`](SliceExt::is_subset_of).
+    ///
+    /// # Time Complexity
+    ///
+    /// This method has O(n + m) average time complexity, where n and m are the lengths of the
+    /// slice and `other`.
+    ///
+    /// # Space Complexity
+    ///
+    /// This method uses O(n) additional space to store the elements of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use est::slice::SliceExt;
+    ///
+    /// assert!([1, 2, 3].is_superset_of(&[3, 3, 1]));
+    /// assert!(![1, 2, 3].is_superset_of(&[4]));
+    /// ```
+    ///
+    /// # Type Requirements
+    ///
+    /// The element type `T` must implement [`Hash`] and [`Eq`] traits to be used
+    /// in the internal [`HashSet`].
+    fn is_superset_of(&self, other: &[T]) -> bool
+    where
+        T: Hash + Eq;
+}
+
+impl<T> SliceExt<T> for [T] {
+    fn has_dup(&self) -> bool
+    where
+        T: Hash + Eq,
+    {
+        let mut seen = HashSet::with_capacity(self.len());
+
+        for item in self {
+            if !seen.insert(item) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn find_dup(&self) -> Option<&T>
+    where
+        T: Hash + Eq,
+    {
+        let mut seen = HashSet::with_capacity(self.len());
+
+        self.iter().find(|&item| !seen.insert(item))
+    }
+
+    fn dup_indices(&self) -> Vec<usize>
+    where
+        T: Hash + Eq,
+    {
+        let mut seen = HashSet::with_capacity(self.len());
+        let mut indices = Vec::new();
+
+        for (i, item) in self.iter().enumerate() {
+            if !seen.insert(item) {
+                indices.push(i);
+            }
+        }
+
+        indices
+    }
+
+    fn count_dups(&self) -> usize
+    where
+        T: Hash + Eq,
+    {
+        let mut seen = HashSet::with_capacity(self.len());
+
+        self.iter().filter(|&item| !seen.insert(item)).count()
+    }
+
+    fn unique(&self) -> Vec<T>
+    where
+        T: Hash + Eq + Clone,
+    {
+        let mut seen = HashSet::with_capacity(self.len());
+
+        self.iter()
+            .filter(|&item| seen.insert(item))
+            .cloned()
+            .collect()
+    }
+
+    fn has_dup_by_key<K, F>(&self, f: F) -> bool
+    where
+        K: Hash + Eq,
+        F: FnMut(&T) -> K,
+    {
+        let mut seen = HashSet::with_capacity(self.len());
+
+        self.iter().map(f).any(|key| !seen.insert(key))
+    }
+
+    fn has_dup_sorted(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.windows(2).any(|pair| pair[0] == pair[1])
+    }
+
+    fn frequencies(&self) -> HashMap<&T, usize>
+    where
+        T: Hash + Eq,
+    {
+        let mut freqs = HashMap::with_capacity(self.len());
+
+        for item in self {
+            *freqs.entry(item).or_insert(0) += 1;
+        }
+
+        freqs
+    }
+
+    fn mode(&self) -> Option<&T>
+    where
+        T: Hash + Eq,
+    {
+        let freqs = self.frequencies();
+        let max = freqs.values().max()?;
+
+        self.iter().find(|item| freqs[item] == *max)
+    }
+
+    fn min_max(&self) -> Option<(&T, &T)>
+    where
+        T: Ord,
+    {
+        let (first, rest) = self.split_first()?;
+        let (mut min, mut max) = (first, first);
+        let mut pairs = rest.chunks_exact(2);
+
+        for pair in &mut pairs {
+            let (small, large) = if pair[1] < pair[0] {
+                (&pair[1], &pair[0])
+            } else {
+                (&pair[0], &pair[1])
+            };
+
+            if small < min {
+                min = small;
+            }
+
+            if large >= max {
+                max = large;
+            }
+        }
+
+        if let [last] = pairs.remainder() {
+            if last < min {
+                min = last;
+            } else if last >= max {
+                max = last;
+            }
+        }
+
+        Some((min, max))
+    }
+
+    fn is_permutation_of(&self, other: &[T]) -> bool
+    where
+        T: Hash + Eq,
+    {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        let mut counts: HashMap<&T, i64> = HashMap::with_capacity(self.len());
+
+        for item in self {
+            *counts.entry(item).or_insert(0) += 1;
+        }
+
+        for item in other {
+            *counts.entry(item).or_insert(0) -= 1;
+        }
+
+        counts.values().all(|&count| count == 0)
+    }
+
+    fn intersect(&self, other: &[T]) -> Vec<T>
+    where
+        T: Hash + Eq + Clone,
+    {
+        let mut other: HashSet<_> = other.iter().collect();
+
+        // Remove the matched elements from `other`, so that each is only collected once.
+        self.iter()
+            .filter(|&item| other.remove(item))
+            .cloned()
+            .collect()
+    }
+
+    fn difference(&self, other: &[T]) -> Vec<T>
+    where
+        T: Hash + Eq + Clone,
+    {
+        // Elements of `other` and the elements already collected are both excluded.
+        let mut excluded: HashSet<_> = other.iter().collect();
+
+        self.iter()
+            .filter(|&item| excluded.insert(item))
+            .cloned()
+            .collect()
+    }
+
+    fn chunk_by_key<K, F>(&self, mut f: F) -> Vec<&[T]>
+    where
+        K: PartialEq,
+        F: FnMut(&T) -> K,
+    {
+        let mut chunks = Vec::new();
+        let Some(first) = self.first() else {
+            return chunks;
+        };
+
+        let mut start = 0;
+        let mut current = f(first);
+
+        for (i, item) in self.iter().enumerate().skip(1) {
+            let key = f(item);
+
+            if key != current {
+                chunks.push(&self[start..i]);
+                start = i;
+                current = key;
+            }
+        }
+
+        chunks.push(&self[start..]);
+        chunks
+    }
+
+    fn adjacent_pairs<'a>(&'a self) -> impl Iterator<Item = (&'a T, &'a T)>
+    where
+        T: 'a,
+    {
+        self.windows(2).map(|w| (&w[0], &w[1]))
+    }
+
+    fn count_occurrences(&self, target: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.iter().filter(|&item| item == target).count()
+    }
+
+    fn equal_range(&self, target: &T) -> Range<usize>
+    where
+        T: Ord,
+    {
+        let lo = self.partition_point(|item| item < target);
+        let hi = lo + self[lo..].partition_point(|item| item <= target);
+        lo..hi
+    }
+
+    fn argsort(&self) -> Vec<usize>
+    where
+        T: Ord,
+    {
+        let mut indices: Vec<_> = (0..self.len()).collect();
+        indices.sort_by(|&a, &b| self[a].cmp(&self[b]));
+        indices
+    }
+
+    fn argsort_by_key<K, F>(&self, mut f: F) -> Vec<usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let mut indices: Vec<_> = (0..self.len()).collect();
+        indices.sort_by_key(|&i| f(&self[i]));
+        indices
+    }
+
+    fn contains_subslice(&self, needle: &[T]) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        if needle.is_empty() {
+            return Some(0);
+        }
+
+        self.windows(needle.len())
+            .position(|window| window == needle)
+    }
+
+    fn replace_all(&mut self, from: &T, to: T) -> usize
+    where
+        T: PartialEq + Clone,
+    {
+        let mut count = 0;
+
+        for item in self.iter_mut().filter(|item| *item == from) {
+            item.clone_from(&to);
+            count += 1;
+        }
+
+        count
+    }
+
+    fn partition_point_by_key<K, F>(&self, key: &K, mut f: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.partition_point(|item| f(item) < *key)
+    }
+
+    fn dedup_with_count(&self) -> Vec<(T, usize)>
+    where
+        T: PartialEq + Clone,
+    {
+        let mut runs: Vec<(T, usize)> = Vec::new();
+
+        for item in self {
+            match runs.last_mut() {
+                Some((value, count)) if value == item => *count += 1,
+                _ => runs.push((item.clone(), 1)),
+            }
+        }
+
+        runs
+    }
+
+    fn is_sorted_by_key_ord<K, F>(&self, mut f: F) -> bool
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.adjacent_pairs().all(|(a, b)| f(a) <= f(b))
+    }
+
+    fn group_counts_sorted(&self) -> Vec<(&T, usize)>
+    where
+        T: PartialEq,
+    {
+        self.chunk_by(|a, b| a == b)
+            .map(|run| (&run[0], run.len()))
+            .collect()
+    }
+
+    #[cfg(feature = "rand")]
+    fn sample(&self, n: usize, rng: &mut impl rand::Rng) -> Vec<&T> {
+        if n == 0 {
+            return Vec::new();
+        }
+
+        if n >= self.len() {
+            return self.iter().collect();
+        }
+
+        let mut reservoir: Vec<_> = self.iter().take(n).collect();
+
+        for (i, item) in self.iter().enumerate().skip(n) {
+            let j = rng.random_range(0..=i);
+
+            if j < n {
+                reservoir[j] = item;
+            }
+        }
+
+        reservoir
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_has_dup(&self) -> bool
+    where
+        T: Hash + Eq + Sync,
+    {
+        use rayon::prelude::*;
+
+        let chunk_size = self.len().div_ceil(rayon::current_num_threads()).max(1);
+
+        self.par_chunks(chunk_size)
+            .map(|chunk| {
+                let mut set = HashSet::with_capacity(chunk.len());
+                chunk.iter().all(|item| set.insert(item)).then_some(set)
+            })
+            .try_reduce(HashSet::new, |a, b| {
+                // Merge the smaller set into the larger one.
+                let (small, mut large) = if a.len() < b.len() { (a, b) } else { (b, a) };
+                small
+                    .into_iter()
+                    .all(|item| large.insert(item))
+                    .then_some(large)
+            })
+            .is_none()
+    }
+
+    fn rotate_to(&mut self, target: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        match self.iter().position(|item| item == target) {
+            Some(mid) => {
+                self.rotate_left(mid);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn split_first_run(&self) -> Option<(&[T], &[T])>
+    where
+        T: PartialEq,
+    {
+        let first = self.first()?;
+        let len = self.iter().take_while(|&item| item == first).count();
+        Some(self.split_at(len))
+    }
+
+    fn split_last_run(&self) -> Option<(&[T], &[T])>
+    where
+        T: PartialEq,
+    {
+        let last = self.last()?;
+        let len = self.iter().rev().take_while(|&item| item == last).count();
+        Some(self.split_at(self.len() - len))
+    }
+
+    fn position_max_by_key<K, F>(&self, mut f: F) -> Option<usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.iter()
+            .enumerate()
+            .max_by_key(|(_, item)| f(item))
+            .map(|(i, _)| i)
+    }
+
+    fn position_min_by_key<K, F>(&self, mut f: F) -> Option<usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.iter()
+            .enumerate()
+            .min_by_key(|(_, item)| f(item))
+            .map(|(i, _)| i)
+    }
+
+    fn interleave(&self, other: &[T]) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let common = self.len().min(other.len());
+        let mut result = Vec::with_capacity(self.len() + other.len());
+
+        for (a, b) in self.iter().zip(other) {
+            result.push(a.clone());
+            result.push(b.clone());
+        }
+
+        result.extend_from_slice(&self[common..]);
+        result.extend_from_slice(&other[common..]);
+        result
+    }
+
+    fn median_cloned(&self) -> Option<T>
+    where
+        T: Ord + Clone,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut scratch = self.to_vec();
+        let (_, median, _) = scratch.select_nth_unstable((self.len() - 1) / 2);
+        Some(median.clone())
+    }
+
+    fn all_equal(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        match self.split_first() {
+            Some((first, rest)) => rest.iter().all(|item| item == first),
+            None => true,
+        }
+    }
+
+    fn first_duplicate_pair(&self) -> Option<(usize, usize)>
+    where
+        T: Hash + Eq,
+    {
+        let mut first_seen = HashMap::with_capacity(self.len());
+
+        self.iter().enumerate().find_map(|(j, item)| {
+            let i = *first_seen.entry(item).or_insert(j);
+            (i != j).then_some((i, j))
+        })
+    }
+
+    fn for_each_window_mut<const N: usize, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut [T]),
+    {
+        assert!(N != 0, "window size must be non-zero");
+
+        for start in 0..self.len().saturating_sub(N - 1) {
+            f(&mut self[start..start + N]);
+        }
+    }
+
+    fn prefix_sum(&self) -> Vec<T>
+    where
+        T: Clone + Add<Output = T>,
+    {
+        let mut sums: Vec<T> = Vec::with_capacity(self.len());
+
+        for item in self {
+            let sum = match sums.last() {
+                Some(acc) => acc.clone() + item.clone(),
+                None => item.clone(),
+            };
+            sums.push(sum);
+        }
+
+        sums
+    }
+
+    fn indices_where<F>(&self, mut pred: F) -> Vec<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .enumerate()
+            .filter_map(|(i, item)| pred(item).then_some(i))
+            .collect()
+    }
+
+    fn chunks_balanced(&self, parts: usize) -> Vec<&[T]> {
+        if parts == 0 {
+            return Vec::new();
+        }
+
+        let (base, remainder) = (self.len() / parts, self.len() % parts);
+        let mut chunks = Vec::with_capacity(parts);
+        let mut rest = self;
+
+        for i in 0..parts {
+            let (chunk, tail) = rest.split_at(base + usize::from(i < remainder));
+            chunks.push(chunk);
+            rest = tail;
+        }
+
+        chunks
+    }
+
+    fn most_common_n(&self, k: usize) -> Vec<(&T, usize)>
+    where
+        T: Hash + Eq,
+    {
+        // Maps each element to the index of its first appearance and its count.
+        let mut counts: HashMap<&T, (usize, usize)> = HashMap::new();
+
+        for (i, item) in self.iter().enumerate() {
+            counts.entry(item).or_insert((i, 0)).1 += 1;
+        }
+
+        let mut ranked: Vec<_> = counts.into_iter().collect();
+        let rank = |&(_, (first, count)): &(&T, (usize, usize))| (Reverse(count), first);
+
+        if k < ranked.len() {
+            if k == 0 {
+                return Vec::new();
+            }
+
+            ranked.select_nth_unstable_by_key(k - 1, rank);
+            ranked.truncate(k);
+        }
+
+        ranked.sort_unstable_by_key(rank);
+        ranked
+            .into_iter()
+            .map(|(item, (_, count))| (item, count))
+            .collect()
+    }
+
+    fn split_when<F>(&self, mut pred: F) -> Vec<&[T]>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.chunk_by(|a, b| !pred(a, b)).collect()
+    }
+
+    fn zip_with<U, R, F>(&self, other: &[U], mut f: F) -> Vec<R>
+    where
+        F: FnMut(&T, &U) -> R,
+    {
+        self.iter().zip(other).map(|(a, b)| f(a, b)).collect()
+    }
+
+    fn is_subset_of(&self, other: &[T]) -> bool
+    where
+        T: Hash + Eq,
+    {
+        let other: HashSet<_> = other.iter().collect();
+
+        self.iter().all(|item| other.contains(item))
+    }
+
+    fn is_superset_of(&self, other: &[T]) -> bool
+    where
+        T: Hash + Eq,
+    {
+        other.is_subset_of(self)
+    }
 }
 
-impl<T> SliceExt<T> for [T] {
-    fn has_dup(&self) -> bool
-    where
-        T: Hash + Eq,
-    {
-        let mut seen = HashSet::with_capacity(self.len());
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_dup_with_duplicates() {
+        let slice = [1, 2, 3, 2, 4];
+        assert!(slice.has_dup());
+    }
+
+    #[test]
+    fn test_has_dup_without_duplicates() {
+        let slice = [1, 2, 3, 4, 5];
+        assert!(!slice.has_dup());
+    }
+
+    #[test]
+    fn test_has_dup_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!(!slice.has_dup());
+    }
+
+    #[test]
+    fn test_has_dup_single_element() {
+        let slice = [42];
+        assert!(!slice.has_dup());
+    }
+
+    #[test]
+    fn test_has_dup_all_same_elements() {
+        let slice = [5, 5, 5, 5];
+        assert!(slice.has_dup());
+    }
+
+    #[test]
+    fn test_has_dup_two_elements_same() {
+        let slice = [1, 1];
+        assert!(slice.has_dup());
+    }
+
+    #[test]
+    fn test_has_dup_two_elements_different() {
+        let slice = [1, 2];
+        assert!(!slice.has_dup());
+    }
+
+    #[test]
+    fn test_has_dup_strings() {
+        let slice = ["hello", "world", "hello"];
+        assert!(slice.has_dup());
+
+        let slice_no_dup = ["hello", "world", "rust"];
+        assert!(!slice_no_dup.has_dup());
+    }
+
+    #[test]
+    fn test_has_dup_large_slice() {
+        // Test with a larger slice to ensure performance
+        let mut vec = Vec::new();
+        for i in 0..1000 {
+            vec.push(i);
+        }
+        assert!(!vec.has_dup());
+
+        // Add a duplicate
+        vec.push(500);
+        assert!(vec.has_dup());
+    }
+
+    #[test]
+    fn test_has_dup_duplicate_at_end() {
+        let slice = [1, 2, 3, 4, 5, 1];
+        assert!(slice.has_dup());
+    }
+
+    #[test]
+    fn test_has_dup_duplicate_at_beginning() {
+        let slice = [1, 1, 2, 3, 4, 5];
+        assert!(slice.has_dup());
+    }
+
+    #[test]
+    fn test_has_dup_with_vec() {
+        let vec = vec![1, 2, 3, 2, 4];
+        assert!(vec.has_dup());
+
+        let vec_no_dup = vec![1, 2, 3, 4, 5];
+        assert!(!vec_no_dup.has_dup());
+    }
+
+    #[test]
+    fn test_has_dup_with_slice_reference() {
+        let array = [1, 2, 3, 2, 4];
+        let slice = &array[..];
+        assert!(slice.has_dup());
+    }
+
+    #[test]
+    fn test_has_dup_chars() {
+        let chars = ['a', 'b', 'c', 'a'];
+        assert!(chars.has_dup());
+
+        let chars_no_dup = ['a', 'b', 'c', 'd'];
+        assert!(!chars_no_dup.has_dup());
+    }
+
+    #[test]
+    fn test_find_dup_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert_eq!(slice.find_dup(), None);
+    }
+
+    #[test]
+    fn test_find_dup_without_duplicates() {
+        let slice = [1, 2, 3, 4, 5];
+        assert_eq!(slice.find_dup(), None);
+    }
+
+    #[test]
+    fn test_find_dup_all_same_elements() {
+        let slice = [5, 5, 5, 5];
+        assert!(std::ptr::eq(slice.find_dup().unwrap(), &slice[1]));
+    }
+
+    #[test]
+    fn test_find_dup_duplicate_at_end() {
+        let slice = [1, 2, 3, 4, 5, 1];
+        assert!(std::ptr::eq(slice.find_dup().unwrap(), &slice[5]));
+    }
+
+    #[test]
+    fn test_find_dup_first_repeated() {
+        let slice = ["a", "b", "c", "b", "a"];
+        assert_eq!(slice.find_dup(), Some(&"b"));
+    }
+
+    #[test]
+    fn test_dup_indices_with_duplicates() {
+        let slice = [1, 1, 2, 1];
+        assert_eq!(slice.dup_indices(), [1, 3]);
+    }
+
+    #[test]
+    fn test_dup_indices_without_duplicates() {
+        let slice = [1, 2, 3, 4, 5];
+        assert!(slice.dup_indices().is_empty());
+    }
+
+    #[test]
+    fn test_dup_indices_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!(slice.dup_indices().is_empty());
+    }
+
+    #[test]
+    fn test_dup_indices_multiple_values() {
+        let slice = ["a", "b", "a", "c", "b", "a"];
+        assert_eq!(slice.dup_indices(), [2, 4, 5]);
+    }
+
+    #[test]
+    fn test_count_dups_all_same_elements() {
+        let slice = [5, 5, 5];
+        assert_eq!(slice.count_dups(), 2);
+    }
+
+    #[test]
+    fn test_count_dups_without_duplicates() {
+        let slice = [1, 2, 3];
+        assert_eq!(slice.count_dups(), 0);
+    }
+
+    #[test]
+    fn test_count_dups_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert_eq!(slice.count_dups(), 0);
+    }
+
+    #[test]
+    fn test_count_dups_multiple_values() {
+        let slice = ["a", "b", "a", "c", "b", "a"];
+        assert_eq!(slice.count_dups(), 3);
+    }
+
+    #[test]
+    fn test_unique_strings_order() {
+        let slice = [
+            String::from("b"),
+            String::from("a"),
+            String::from("b"),
+            String::from("c"),
+            String::from("a"),
+        ];
+        assert_eq!(slice.unique(), ["b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_unique_without_duplicates() {
+        let slice = [3, 1, 2];
+        assert_eq!(slice.unique(), [3, 1, 2]);
+    }
+
+    #[test]
+    fn test_unique_all_same_elements() {
+        let slice = [5, 5, 5, 5];
+        assert_eq!(slice.unique(), [5]);
+    }
+
+    #[test]
+    fn test_unique_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!(slice.unique().is_empty());
+    }
+
+    #[derive(Debug)]
+    struct Person {
+        id: u32,
+        name: &'static str,
+    }
+
+    fn people() -> [Person; 3] {
+        [
+            Person {
+                id: 1,
+                name: "Alice",
+            },
+            Person { id: 2, name: "Bob" },
+            Person {
+                id: 1,
+                name: "Carol",
+            },
+        ]
+    }
+
+    #[test]
+    fn test_has_dup_by_key_with_duplicates() {
+        assert!(people().has_dup_by_key(|p| p.id));
+    }
+
+    #[test]
+    fn test_has_dup_by_key_without_duplicates() {
+        assert!(!people().has_dup_by_key(|p| p.name));
+    }
+
+    #[test]
+    fn test_has_dup_by_key_derived_key() {
+        assert!(people().has_dup_by_key(|p| p.name.len()));
+        assert!(!people().has_dup_by_key(|p| (p.id, p.name)));
+    }
+
+    #[test]
+    fn test_has_dup_by_key_empty_slice() {
+        let slice: [Person; 0] = [];
+        assert!(!slice.has_dup_by_key(|p| p.id));
+    }
+
+    #[test]
+    fn test_has_dup_sorted_matches_has_dup() {
+        let slices: [&[i32]; 6] = [
+            &[],
+            &[42],
+            &[1, 2, 3, 4, 5],
+            &[1, 1, 2, 3],
+            &[1, 2, 3, 3],
+            &[5, 5, 5, 5],
+        ];
+
+        for slice in slices {
+            assert_eq!(slice.has_dup_sorted(), slice.has_dup());
+        }
+    }
+
+    #[test]
+    fn test_has_dup_sorted_strings() {
+        let mut arr = ["world", "hello", "rust", "hello"];
+        arr.sort();
+        assert!(arr.has_dup_sorted());
+    }
+
+    #[test]
+    fn test_frequencies_with_duplicates() {
+        let slice = ['a', 'a', 'b'];
+        assert_eq!(slice.frequencies(), HashMap::from([(&'a', 2), (&'b', 1)]));
+    }
+
+    #[test]
+    fn test_frequencies_without_duplicates() {
+        let slice = [1, 2, 3];
+        let freqs = slice.frequencies();
+        assert_eq!(freqs.len(), 3);
+        assert!(freqs.values().all(|&count| count == 1));
+    }
+
+    #[test]
+    fn test_frequencies_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!(slice.frequencies().is_empty());
+    }
+
+    #[test]
+    fn test_mode_tie_first_seen() {
+        let slice = [1, 2, 2, 3, 3];
+        assert_eq!(slice.mode(), Some(&2));
+
+        let slice = [3, 3, 2, 2, 1];
+        assert_eq!(slice.mode(), Some(&3));
+    }
+
+    #[test]
+    fn test_mode_single_winner() {
+        let slice = ["a", "b", "b", "c", "b", "a"];
+        assert_eq!(slice.mode(), Some(&"b"));
+    }
+
+    #[test]
+    fn test_mode_first_occurrence() {
+        let slice = [1, 2, 1];
+        assert!(std::ptr::eq(slice.mode().unwrap(), &slice[0]));
+    }
+
+    #[test]
+    fn test_mode_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert_eq!(slice.mode(), None);
+    }
+
+    #[test]
+    fn test_min_max_single_element() {
+        let slice = [42];
+        let (min, max) = slice.min_max().unwrap();
+        assert!(std::ptr::eq(min, &slice[0]));
+        assert!(std::ptr::eq(max, &slice[0]));
+    }
+
+    #[test]
+    fn test_min_max_references_into_slice() {
+        let slice = [3, 1, 4, 1, 5, 9, 2, 6];
+        let (min, max) = slice.min_max().unwrap();
+        assert!(std::ptr::eq(min, &slice[1]));
+        assert!(std::ptr::eq(max, &slice[5]));
+    }
+
+    #[test]
+    fn test_min_max_ties() {
+        let slice = [2, 2, 2, 2, 2];
+        let (min, max) = slice.min_max().unwrap();
+        assert!(std::ptr::eq(min, &slice[0]));
+        assert!(std::ptr::eq(max, &slice[4]));
+    }
+
+    #[test]
+    fn test_min_max_matches_min_and_max() {
+        let vec: Vec<i32> = (0..100).map(|i| (i * 37 + 11) % 101 - 50).collect();
+
+        for len in 0..vec.len() {
+            let slice = &vec[..len];
+            assert_eq!(slice.min_max(), slice.iter().min().zip(slice.iter().max()));
+        }
+    }
+
+    #[test]
+    fn test_min_max_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert_eq!(slice.min_max(), None);
+    }
+
+    #[test]
+    fn test_is_permutation_of_true() {
+        assert!([1, 2, 2].is_permutation_of(&[2, 1, 2]));
+        assert!(["a", "b", "c"].is_permutation_of(&["c", "a", "b"]));
+    }
+
+    #[test]
+    fn test_is_permutation_of_different_multiplicities() {
+        assert!(![1, 2].is_permutation_of(&[1, 1]));
+        assert!(![1, 1, 2].is_permutation_of(&[1, 2, 2]));
+    }
+
+    #[test]
+    fn test_is_permutation_of_different_lengths() {
+        assert!(![1, 2].is_permutation_of(&[1, 2, 2]));
+    }
+
+    #[test]
+    fn test_is_permutation_of_empty_slices() {
+        let slice: [i32; 0] = [];
+        assert!(slice.is_permutation_of(&[]));
+        assert!(!slice.is_permutation_of(&[1]));
+    }
+
+    #[test]
+    fn test_intersect_overlap() {
+        let slice = [1, 2, 3, 4];
+        assert_eq!(slice.intersect(&[4, 2, 6]), [2, 4]);
+    }
+
+    #[test]
+    fn test_intersect_disjoint() {
+        let slice = [1, 2, 3];
+        assert!(slice.intersect(&[4, 5, 6]).is_empty());
+    }
+
+    #[test]
+    fn test_intersect_duplicates() {
+        let slice = ["b", "a", "b", "c", "a"];
+        assert_eq!(slice.intersect(&["a", "a", "b"]), ["b", "a"]);
+    }
+
+    #[test]
+    fn test_intersect_empty_slices() {
+        let slice: [i32; 0] = [];
+        assert!(slice.intersect(&[1, 2]).is_empty());
+        assert!([1, 2].intersect(&slice).is_empty());
+    }
+
+    #[test]
+    fn test_difference_dedup() {
+        let slice = [1, 2, 2, 3];
+        assert_eq!(slice.difference(&[2]), [1, 3]);
+
+        let slice = [3, 1, 3, 1];
+        assert_eq!(slice.difference(&[]), [3, 1]);
+    }
+
+    #[test]
+    fn test_difference_disjoint() {
+        let slice = ["c", "a", "b"];
+        assert_eq!(slice.difference(&["x", "y"]), ["c", "a", "b"]);
+    }
+
+    #[test]
+    fn test_difference_subset() {
+        let slice = [1, 2, 3];
+        assert!(slice.difference(&[3, 2, 1, 0]).is_empty());
+    }
+
+    #[test]
+    fn test_difference_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!(slice.difference(&[1, 2]).is_empty());
+    }
+
+    #[test]
+    fn test_chunk_by_key_identity() {
+        let slice = [1, 1, 2, 2, 2, 1];
+        let chunks = slice.chunk_by_key(|&x| x);
+        assert_eq!(
+            chunks.iter().map(|c| c.len()).collect::<Vec<_>>(),
+            [2, 3, 1]
+        );
+        assert_eq!(chunks, [&[1, 1][..], &[2, 2, 2], &[1]]);
+    }
+
+    #[test]
+    fn test_chunk_by_key_derived_key() {
+        let slice = [1, 3, 5, 2, 4, 7];
+        let chunks = slice.chunk_by_key(|x| x % 2);
+        assert_eq!(chunks, [&[1, 3, 5][..], &[2, 4], &[7]]);
+    }
+
+    #[test]
+    fn test_chunk_by_key_single_chunk() {
+        let slice = ["a", "b", "c"];
+        assert_eq!(slice.chunk_by_key(|_| ()), [&slice[..]]);
+    }
+
+    #[test]
+    fn test_chunk_by_key_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!(slice.chunk_by_key(|&x| x).is_empty());
+    }
+
+    #[test]
+    fn test_adjacent_pairs_order() {
+        let slice = ["a", "b", "c", "d"];
+        let pairs: Vec<_> = slice.adjacent_pairs().collect();
+        assert_eq!(pairs, [(&"a", &"b"), (&"b", &"c"), (&"c", &"d")]);
+    }
+
+    #[test]
+    fn test_adjacent_pairs_two_elements() {
+        let slice = [1, 2];
+        assert_eq!(slice.adjacent_pairs().collect::<Vec<_>>(), [(&1, &2)]);
+    }
+
+    #[test]
+    fn test_adjacent_pairs_single_element() {
+        let slice = [1];
+        assert_eq!(slice.adjacent_pairs().count(), 0);
+    }
+
+    #[test]
+    fn test_adjacent_pairs_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert_eq!(slice.adjacent_pairs().count(), 0);
+    }
+
+    #[test]
+    fn test_count_occurrences_with_duplicates() {
+        let slice = [1, 2, 1, 3, 1];
+        assert_eq!(slice.count_occurrences(&1), 3);
+        assert_eq!(slice.count_occurrences(&2), 1);
+    }
+
+    #[test]
+    fn test_count_occurrences_no_match() {
+        let slice = ["a", "b", "c"];
+        assert_eq!(slice.count_occurrences(&"d"), 0);
+    }
+
+    #[test]
+    fn test_count_occurrences_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert_eq!(slice.count_occurrences(&1), 0);
+    }
+
+    #[test]
+    fn test_equal_range_runs() {
+        let slice = [1, 1, 2, 2, 2, 3, 5, 5];
+        assert_eq!(slice.equal_range(&1), 0..2);
+        assert_eq!(slice.equal_range(&2), 2..5);
+        assert_eq!(slice.equal_range(&3), 5..6);
+        assert_eq!(slice.equal_range(&5), 6..8);
+    }
+
+    #[test]
+    fn test_equal_range_missing() {
+        let slice = [1, 3, 3, 7];
+        assert_eq!(slice.equal_range(&0), 0..0);
+        assert_eq!(slice.equal_range(&2), 1..1);
+        assert_eq!(slice.equal_range(&8), 4..4);
+    }
+
+    #[test]
+    fn test_equal_range_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert_eq!(slice.equal_range(&1), 0..0);
+    }
+
+    #[test]
+    fn test_argsort_order() {
+        let slice = [5, 3, 9, 1];
+        let order = slice.argsort();
+        assert_eq!(order, [3, 1, 0, 2]);
+        assert!(order.windows(2).all(|w| slice[w[0]] <= slice[w[1]]));
+    }
+
+    #[test]
+    fn test_argsort_stable() {
+        let slice = [2, 1, 2, 1, 2];
+        assert_eq!(slice.argsort(), [1, 3, 0, 2, 4]);
+    }
+
+    #[test]
+    fn test_argsort_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!(slice.argsort().is_empty());
+    }
+
+    #[test]
+    fn test_argsort_by_key_stable() {
+        let slice = ["bb", "a", "cc", "d", "eee"];
+        assert_eq!(slice.argsort_by_key(|s| s.len()), [1, 3, 0, 2, 4]);
+    }
+
+    #[test]
+    fn test_argsort_by_key_reverse() {
+        let slice = [1, 3, 2];
+        assert_eq!(slice.argsort_by_key(|&x| std::cmp::Reverse(x)), [1, 2, 0]);
+    }
+
+    #[test]
+    fn test_contains_subslice_overlapping_candidates() {
+        let slice = [1, 1, 2];
+        assert_eq!(slice.contains_subslice(&[1, 2]), Some(1));
+
+        let slice = [1, 1, 1, 2];
+        assert_eq!(slice.contains_subslice(&[1, 1, 2]), Some(1));
+    }
+
+    #[test]
+    fn test_contains_subslice_first_match() {
+        let slice = ["a", "b", "a", "b"];
+        assert_eq!(slice.contains_subslice(&["a", "b"]), Some(0));
+        assert_eq!(slice.contains_subslice(&["b", "a", "b"]), Some(1));
+    }
+
+    #[test]
+    fn test_contains_subslice_no_match() {
+        let slice = [1, 2, 3];
+        assert_eq!(slice.contains_subslice(&[3, 4]), None);
+        assert_eq!(slice.contains_subslice(&[1, 2, 3, 4]), None);
+    }
+
+    #[test]
+    fn test_contains_subslice_empty_needle() {
+        let slice = [1, 2, 3];
+        assert_eq!(slice.contains_subslice(&[]), Some(0));
+
+        let slice: [i32; 0] = [];
+        assert_eq!(slice.contains_subslice(&[]), Some(0));
+        assert_eq!(slice.contains_subslice(&[1]), None);
+    }
+
+    #[test]
+    fn test_replace_all_count() {
+        let mut slice = [1, 0, 2, 0, 3, 0];
+        assert_eq!(slice.replace_all(&0, 7), 3);
+        assert_eq!(slice, [1, 7, 2, 7, 3, 7]);
+    }
+
+    #[test]
+    fn test_replace_all_strings() {
+        let mut slice = vec![String::from("a"), String::from("b"), String::from("a")];
+        assert_eq!(slice.replace_all(&String::from("a"), String::from("z")), 2);
+        assert_eq!(slice, ["z", "b", "z"]);
+    }
+
+    #[test]
+    fn test_replace_all_no_match() {
+        let mut slice = [1, 2, 3];
+        assert_eq!(slice.replace_all(&4, 0), 0);
+        assert_eq!(slice, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_replace_all_empty_slice() {
+        let mut slice: [i32; 0] = [];
+        assert_eq!(slice.replace_all(&1, 2), 0);
+    }
+
+    #[test]
+    fn test_partition_point_by_key_struct() {
+        let people = [
+            Person {
+                id: 1,
+                name: "Alice",
+            },
+            Person { id: 3, name: "Bob" },
+            Person {
+                id: 3,
+                name: "Carol",
+            },
+            Person {
+                id: 7,
+                name: "Dave",
+            },
+        ];
+
+        for key in 0..9 {
+            let expected = people
+                .iter()
+                .position(|p| p.id >= key)
+                .unwrap_or(people.len());
+            assert_eq!(people.partition_point_by_key(&key, |p| p.id), expected);
+        }
+    }
+
+    #[test]
+    fn test_partition_point_by_key_derived() {
+        let words = ["a", "bb", "cc", "dddd"];
+        assert_eq!(words.partition_point_by_key(&2, |w| w.len()), 1);
+        assert_eq!(words.partition_point_by_key(&3, |w| w.len()), 3);
+    }
+
+    #[test]
+    fn test_partition_point_by_key_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert_eq!(slice.partition_point_by_key(&1, |&x| x), 0);
+    }
+
+    #[test]
+    fn test_dedup_with_count_runs() {
+        let slice = ["a", "a", "b", "a"];
+        assert_eq!(slice.dedup_with_count(), [("a", 2), ("b", 1), ("a", 1)]);
+    }
+
+    #[test]
+    fn test_dedup_with_count_single_run() {
+        let slice = [7; 5];
+        assert_eq!(slice.dedup_with_count(), [(7, 5)]);
+    }
+
+    #[test]
+    fn test_dedup_with_count_without_duplicates() {
+        let slice = [1, 2, 3];
+        assert_eq!(slice.dedup_with_count(), [(1, 1), (2, 1), (3, 1)]);
+    }
+
+    #[test]
+    fn test_dedup_with_count_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!(slice.dedup_with_count().is_empty());
+    }
+
+    #[test]
+    fn test_is_sorted_by_key_ord_ascending() {
+        let slice = [1, 2, 2, 5, 9];
+        assert!(slice.is_sorted_by_key_ord(|&x| x));
+    }
+
+    #[test]
+    fn test_is_sorted_by_key_ord_descending() {
+        let slice = [9, 5, 2, 1];
+        assert!(!slice.is_sorted_by_key_ord(|&x| x));
+        assert!(slice.is_sorted_by_key_ord(|&x| Reverse(x)));
+    }
+
+    #[test]
+    fn test_is_sorted_by_key_ord_equal_keys() {
+        let slice = ["b", "a", "d", "c"];
+        assert!(slice.is_sorted_by_key_ord(|s| s.len()));
+    }
+
+    #[test]
+    fn test_is_sorted_by_key_ord_short_slices() {
+        let slice: [i32; 0] = [];
+        assert!(slice.is_sorted_by_key_ord(|&x| x));
+        assert!([1].is_sorted_by_key_ord(|&x| x));
+    }
+
+    #[test]
+    fn test_group_counts_sorted_matches_frequencies() {
+        let slice = [1, 1, 2, 4, 4, 4, 7];
+        let mut frequencies: Vec<_> = slice.frequencies().into_iter().collect();
+        frequencies.sort();
+        assert_eq!(slice.group_counts_sorted(), frequencies);
+    }
+
+    #[test]
+    fn test_group_counts_sorted_partial_eq_only() {
+        let slice = [0.5, 0.5, 1.5];
+        assert_eq!(slice.group_counts_sorted(), [(&0.5, 2), (&1.5, 1)]);
+    }
+
+    #[test]
+    fn test_group_counts_sorted_without_duplicates() {
+        let slice = ["a", "b", "c"];
+        assert_eq!(
+            slice.group_counts_sorted(),
+            [(&"a", 1), (&"b", 1), (&"c", 1)]
+        );
+    }
+
+    #[test]
+    fn test_group_counts_sorted_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!(slice.group_counts_sorted().is_empty());
+    }
+
+    /// An RNG that panics when used, to check that no random number is drawn.
+    #[cfg(feature = "rand")]
+    struct NoDrawRng;
+
+    #[cfg(feature = "rand")]
+    impl rand::RngCore for NoDrawRng {
+        fn next_u32(&mut self) -> u32 {
+            panic!("no random number should be drawn")
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            panic!("no random number should be drawn")
+        }
+
+        fn fill_bytes(&mut self, _: &mut [u8]) {
+            panic!("no random number should be drawn")
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample_more_than_len() {
+        let slice = [1, 2, 3];
+        let mut sample = slice.sample(5, &mut NoDrawRng);
+        sample.sort();
+        assert_eq!(sample, [&1, &2, &3]);
+        assert_eq!(slice.sample(3, &mut NoDrawRng), [&1, &2, &3]);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample_zero() {
+        let slice = [1, 2, 3];
+        assert!(slice.sample(0, &mut NoDrawRng).is_empty());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample_distinct() {
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let slice: Vec<_> = (0..100).collect();
+        let mut rng = StdRng::seed_from_u64(29);
+
+        for n in [0, 1, 10, 99, 100] {
+            let sample = slice.sample(n, &mut rng);
+            assert_eq!(sample.len(), n);
+            assert!(!sample.has_dup());
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample_covers_all_elements() {
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let slice = [0, 1, 2, 3, 4];
+        let mut rng = StdRng::seed_from_u64(29);
+        let mut seen = [false; 5];
+
+        for _ in 0..100 {
+            for &&item in &slice.sample(2, &mut rng) {
+                seen[item] = true;
+            }
+        }
+
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!(slice.sample(3, &mut rand::rng()).is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_has_dup_matches_has_dup() {
+        let cases: [Vec<i32>; 6] = [
+            vec![],
+            vec![1],
+            vec![1, 1],
+            (0..10_000).collect(),
+            (0..10_000).chain([0]).collect(),
+            (0..10_000).chain(5_000..5_001).collect(),
+        ];
 
-        for item in self {
-            if !seen.insert(item) {
-                return true;
-            }
+        for slice in &cases {
+            assert_eq!(slice.par_has_dup(), slice.has_dup());
         }
+    }
 
-        false
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_has_dup_across_chunks() {
+        let mut slice: Vec<_> = (0..100_000).map(|i| i.to_string()).collect();
+        assert!(!slice.par_has_dup());
+
+        slice.push(String::from("0"));
+        assert!(slice.par_has_dup());
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_rotate_to_found() {
+        let mut slice = [3, 1, 2];
+        assert!(slice.rotate_to(&1));
+        assert_eq!(slice, [1, 2, 3]);
+    }
 
     #[test]
-    fn test_has_dup_with_duplicates() {
-        let slice = [1, 2, 3, 2, 4];
-        assert!(slice.has_dup());
+    fn test_rotate_to_first_occurrence() {
+        let mut slice = ["a", "b", "c", "b", "d"];
+        assert!(slice.rotate_to(&"b"));
+        assert_eq!(slice, ["b", "c", "b", "d", "a"]);
     }
 
     #[test]
-    fn test_has_dup_without_duplicates() {
-        let slice = [1, 2, 3, 4, 5];
-        assert!(!slice.has_dup());
+    fn test_rotate_to_already_first() {
+        let mut slice = [1, 2, 3];
+        assert!(slice.rotate_to(&1));
+        assert_eq!(slice, [1, 2, 3]);
     }
 
     #[test]
-    fn test_has_dup_empty_slice() {
+    fn test_rotate_to_missing() {
+        let mut slice = [3, 1, 2];
+        assert!(!slice.rotate_to(&4));
+        assert_eq!(slice, [3, 1, 2]);
+
+        let mut slice: [i32; 0] = [];
+        assert!(!slice.rotate_to(&1));
+    }
+
+    #[test]
+    fn test_split_first_run_mixed() {
+        let slice = [1, 1, 2, 3];
+        assert_eq!(slice.split_first_run(), Some((&[1, 1][..], &[2, 3][..])));
+    }
+
+    #[test]
+    fn test_split_first_run_single_element() {
+        let slice = [1];
+        assert_eq!(slice.split_first_run(), Some((&[1][..], &[][..])));
+    }
+
+    #[test]
+    fn test_split_first_run_all_same_elements() {
+        let slice = [7; 4];
+        assert_eq!(slice.split_first_run(), Some((&slice[..], &[][..])));
+    }
+
+    #[test]
+    fn test_split_first_run_empty_slice() {
         let slice: [i32; 0] = [];
-        assert!(!slice.has_dup());
+        assert_eq!(slice.split_first_run(), None);
     }
 
     #[test]
-    fn test_has_dup_single_element() {
+    fn test_split_last_run_mixed() {
+        let slice = [1, 2, 3, 3];
+        assert_eq!(slice.split_last_run(), Some((&[1, 2][..], &[3, 3][..])));
+    }
+
+    #[test]
+    fn test_split_last_run_single_element() {
+        let slice = [1];
+        assert_eq!(slice.split_last_run(), Some((&[][..], &[1][..])));
+    }
+
+    #[test]
+    fn test_split_last_run_all_same_elements() {
+        let slice = [7; 4];
+        assert_eq!(slice.split_last_run(), Some((&[][..], &slice[..])));
+    }
+
+    #[test]
+    fn test_split_last_run_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert_eq!(slice.split_last_run(), None);
+    }
+
+    #[test]
+    fn test_position_max_by_key_ties_last() {
+        let slice = [3, 7, 1, 7, 2];
+        assert_eq!(slice.position_max_by_key(|&x| x), Some(3));
+    }
+
+    #[test]
+    fn test_position_max_by_key_derived() {
+        let slice = [-5, 3, 4, -1];
+        assert_eq!(slice.position_max_by_key(|x: &i32| x.abs()), Some(0));
+    }
+
+    #[test]
+    fn test_position_max_by_key_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert_eq!(slice.position_max_by_key(|&x| x), None);
+    }
+
+    #[test]
+    fn test_position_min_by_key_ties_first() {
+        let slice = [3, 1, 7, 1, 2];
+        assert_eq!(slice.position_min_by_key(|&x| x), Some(1));
+    }
+
+    #[test]
+    fn test_position_min_by_key_derived() {
+        let slice = ["ccc", "a", "bb", "d"];
+        assert_eq!(slice.position_min_by_key(|s| s.len()), Some(1));
+    }
+
+    #[test]
+    fn test_position_min_by_key_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert_eq!(slice.position_min_by_key(|&x| x), None);
+    }
+
+    #[test]
+    fn test_interleave_equal_lengths() {
+        let slice = ["a", "c"];
+        assert_eq!(slice.interleave(&["b", "d"]), ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_interleave_longer_self() {
+        let slice = [1, 3, 5, 6];
+        assert_eq!(slice.interleave(&[2, 4]), [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_interleave_longer_other() {
+        let slice = [1];
+        assert_eq!(slice.interleave(&[2, 3, 4]), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_interleave_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert_eq!(slice.interleave(&[1, 2]), [1, 2]);
+        assert_eq!([1, 2].interleave(&slice), [1, 2]);
+        assert!(slice.interleave(&slice).is_empty());
+    }
+
+    #[test]
+    fn test_median_cloned_odd_length() {
+        let slice = [9, 2, 7, 4, 5];
+        assert_eq!(slice.median_cloned(), Some(5));
+        assert_eq!(slice, [9, 2, 7, 4, 5]);
+    }
+
+    #[test]
+    fn test_median_cloned_even_length() {
+        let slice = [8, 2, 6, 4];
+        assert_eq!(slice.median_cloned(), Some(4));
+
+        let slice = [String::from("b"), String::from("a")];
+        assert_eq!(slice.median_cloned().as_deref(), Some("a"));
+    }
+
+    #[test]
+    fn test_median_cloned_single_element() {
         let slice = [42];
-        assert!(!slice.has_dup());
+        assert_eq!(slice.median_cloned(), Some(42));
     }
 
     #[test]
-    fn test_has_dup_all_same_elements() {
-        let slice = [5, 5, 5, 5];
-        assert!(slice.has_dup());
+    fn test_median_cloned_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert_eq!(slice.median_cloned(), None);
     }
 
     #[test]
-    fn test_has_dup_two_elements_same() {
-        let slice = [1, 1];
-        assert!(slice.has_dup());
+    fn test_all_equal_same_elements() {
+        let slice = ["a", "a", "a"];
+        assert!(slice.all_equal());
     }
 
     #[test]
-    fn test_has_dup_two_elements_different() {
+    fn test_all_equal_different_elements() {
+        let slice = [1, 1, 2];
+        assert!(!slice.all_equal());
+
+        let slice = [2, 1, 1];
+        assert!(!slice.all_equal());
+    }
+
+    #[test]
+    fn test_all_equal_single_element() {
+        let slice = [1];
+        assert!(slice.all_equal());
+    }
+
+    #[test]
+    fn test_all_equal_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!(slice.all_equal());
+    }
+
+    #[test]
+    fn test_first_duplicate_pair_alternating() {
+        let slice = ["a", "b", "a", "b"];
+        assert_eq!(slice.first_duplicate_pair(), Some((0, 2)));
+    }
+
+    #[test]
+    fn test_first_duplicate_pair_earliest_repeat() {
+        let slice = [1, 2, 3, 3, 1];
+        assert_eq!(slice.first_duplicate_pair(), Some((2, 3)));
+    }
+
+    #[test]
+    fn test_first_duplicate_pair_without_duplicates() {
+        let slice = [1, 2, 3];
+        assert_eq!(slice.first_duplicate_pair(), None);
+    }
+
+    #[test]
+    fn test_first_duplicate_pair_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert_eq!(slice.first_duplicate_pair(), None);
+    }
+
+    #[test]
+    fn test_for_each_window_mut_smoothing() {
+        let mut slice = [0.0, 3.0, 0.0, 3.0, 0.0];
+        slice.for_each_window_mut::<3, _>(|w| w[1] = (w[0] + w[1] + w[2]) / 3.0);
+        assert_eq!(slice, [0.0, 1.0, 4.0 / 3.0, 13.0 / 9.0, 0.0]);
+    }
+
+    #[test]
+    fn test_for_each_window_mut_window_count() {
+        let mut slice = [0; 5];
+        let mut calls = 0;
+        slice.for_each_window_mut::<2, _>(|w| {
+            assert_eq!(w.len(), 2);
+            w[0] += 1;
+            w[1] += 1;
+            calls += 1;
+        });
+        assert_eq!(calls, 4);
+        assert_eq!(slice, [1, 2, 2, 2, 1]);
+    }
+
+    #[test]
+    fn test_for_each_window_mut_shorter_than_window() {
+        let mut slice = [1, 2];
+        slice.for_each_window_mut::<3, _>(|_| panic!("should not be called"));
+
+        let mut slice: [i32; 0] = [];
+        slice.for_each_window_mut::<1, _>(|_| panic!("should not be called"));
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn test_for_each_window_mut_zero_size() {
+        let mut slice = [1, 2];
+        slice.for_each_window_mut::<0, _>(|_| {});
+    }
+
+    #[test]
+    fn test_prefix_sum_integers() {
+        let slice = [3, -1, 4, 1, 5];
+        assert_eq!(slice.prefix_sum(), [3, 2, 6, 7, 12]);
+    }
+
+    #[test]
+    fn test_prefix_sum_floats() {
+        let slice = [0.5, 0.25, 1.0];
+        assert_eq!(slice.prefix_sum(), [0.5, 0.75, 1.75]);
+    }
+
+    #[test]
+    fn test_prefix_sum_single_element() {
+        let slice = [42];
+        assert_eq!(slice.prefix_sum(), [42]);
+    }
+
+    #[test]
+    fn test_prefix_sum_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!(slice.prefix_sum().is_empty());
+    }
+
+    #[test]
+    fn test_indices_where_some() {
+        let slice = ["a", "bb", "c", "dd"];
+        assert_eq!(slice.indices_where(|s| s.len() == 2), [1, 3]);
+    }
+
+    #[test]
+    fn test_indices_where_all() {
+        let slice = [2, 4, 6];
+        assert_eq!(slice.indices_where(|x| x % 2 == 0), [0, 1, 2]);
+    }
+
+    #[test]
+    fn test_indices_where_none() {
+        let slice = [1, 3, 5];
+        assert!(slice.indices_where(|x| x % 2 == 0).is_empty());
+    }
+
+    #[test]
+    fn test_indices_where_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!(slice.indices_where(|_| true).is_empty());
+    }
+
+    #[test]
+    fn test_chunks_balanced_remainder_first() {
+        let slice: Vec<_> = (0..10).collect();
+        let chunks = slice.chunks_balanced(3);
+        assert_eq!(
+            chunks.iter().map(|c| c.len()).collect::<Vec<_>>(),
+            [4, 3, 3]
+        );
+        assert_eq!(chunks.concat(), slice);
+    }
+
+    #[test]
+    fn test_chunks_balanced_even_split() {
+        let slice = [1, 2, 3, 4, 5, 6];
+        assert_eq!(slice.chunks_balanced(2), [&[1, 2, 3][..], &[4, 5, 6]]);
+    }
+
+    #[test]
+    fn test_chunks_balanced_more_parts_than_elements() {
         let slice = [1, 2];
-        assert!(!slice.has_dup());
+        assert_eq!(slice.chunks_balanced(4), [&[1][..], &[2], &[], &[]]);
     }
 
     #[test]
-    fn test_has_dup_strings() {
-        let slice = ["hello", "world", "hello"];
-        assert!(slice.has_dup());
+    fn test_chunks_balanced_zero_parts() {
+        let slice = [1, 2, 3];
+        assert!(slice.chunks_balanced(0).is_empty());
+    }
 
-        let slice_no_dup = ["hello", "world", "rust"];
-        assert!(!slice_no_dup.has_dup());
+    #[test]
+    fn test_chunks_balanced_empty_slice() {
+        let slice: [i32; 0] = [];
+        let chunks = slice.chunks_balanced(2);
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks.iter().all(|c| c.is_empty()));
     }
 
     #[test]
-    fn test_has_dup_large_slice() {
-        // Test with a larger slice to ensure performance
-        let mut vec = Vec::new();
-        for i in 0..1000 {
-            vec.push(i);
-        }
-        assert!(!vec.has_dup());
+    fn test_most_common_n_top_k() {
+        let slice = [3, 1, 3, 2, 3, 2, 4];
+        assert_eq!(slice.most_common_n(2), [(&3, 3), (&2, 2)]);
+    }
 
-        // Add a duplicate
-        vec.push(500);
-        assert!(vec.has_dup());
+    #[test]
+    fn test_most_common_n_ties_first_seen() {
+        let slice = ["b", "a", "c", "a", "b", "c", "d"];
+        assert_eq!(slice.most_common_n(2), [(&"b", 2), (&"a", 2)]);
+        assert_eq!(slice.most_common_n(3), [(&"b", 2), (&"a", 2), (&"c", 2)]);
     }
 
     #[test]
-    fn test_has_dup_duplicate_at_end() {
-        let slice = [1, 2, 3, 4, 5, 1];
-        assert!(slice.has_dup());
+    fn test_most_common_n_k_larger_than_distinct() {
+        let slice = [1, 2, 2, 3];
+        assert_eq!(slice.most_common_n(10), [(&2, 2), (&1, 1), (&3, 1)]);
     }
 
     #[test]
-    fn test_has_dup_duplicate_at_beginning() {
-        let slice = [1, 1, 2, 3, 4, 5];
-        assert!(slice.has_dup());
+    fn test_most_common_n_zero_and_empty() {
+        let slice = [1, 1, 2];
+        assert!(slice.most_common_n(0).is_empty());
+
+        let slice: [i32; 0] = [];
+        assert!(slice.most_common_n(3).is_empty());
     }
 
     #[test]
-    fn test_has_dup_with_vec() {
-        let vec = vec![1, 2, 3, 2, 4];
-        assert!(vec.has_dup());
+    fn test_split_when_decreasing() {
+        let slice = [1, 2, 1, 3];
+        assert_eq!(slice.split_when(|a, b| a > b), [&[1, 2][..], &[1, 3]]);
+    }
 
-        let vec_no_dup = vec![1, 2, 3, 4, 5];
-        assert!(!vec_no_dup.has_dup());
+    #[test]
+    fn test_split_when_gaps() {
+        let slice = [1, 2, 3, 7, 8, 10];
+        let chunks = slice.split_when(|a, b| b - a > 1);
+        assert_eq!(chunks, [&[1, 2, 3][..], &[7, 8], &[10]]);
     }
 
     #[test]
-    fn test_has_dup_with_slice_reference() {
-        let array = [1, 2, 3, 2, 4];
-        let slice = &array[..];
-        assert!(slice.has_dup());
+    fn test_split_when_never() {
+        let slice = ["a", "b"];
+        assert_eq!(slice.split_when(|_, _| false), [&slice[..]]);
     }
 
     #[test]
-    fn test_has_dup_chars() {
-        let chars = ['a', 'b', 'c', 'a'];
-        assert!(chars.has_dup());
+    fn test_split_when_always() {
+        let slice = [1, 2, 3];
+        assert_eq!(slice.split_when(|_, _| true), [&[1][..], &[2], &[3]]);
+    }
 
-        let chars_no_dup = ['a', 'b', 'c', 'd'];
-        assert!(!chars_no_dup.has_dup());
+    #[test]
+    fn test_split_when_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!(slice.split_when(|_, _| true).is_empty());
+    }
+
+    #[test]
+    fn test_zip_with_equal_lengths() {
+        let slice = [1, 2, 3];
+        assert_eq!(slice.zip_with(&[10, 20, 30], |a, b| a + b), [11, 22, 33]);
+    }
+
+    #[test]
+    fn test_zip_with_different_types() {
+        let slice = ["a", "b"];
+        let result = slice.zip_with(&[2, 3], |s, &n| s.repeat(n));
+        assert_eq!(result, ["aa", "bbb"]);
+    }
+
+    #[test]
+    fn test_zip_with_unequal_lengths() {
+        let slice = [1, 2, 3, 4];
+        assert_eq!(slice.zip_with(&[1, 1], |a, b| a - b), [0, 1]);
+        assert_eq!([5].zip_with(&[1, 2, 3], |a, b| a * b), [5]);
+    }
+
+    #[test]
+    fn test_zip_with_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!(slice.zip_with(&[1, 2], |a, b| a + b).is_empty());
+    }
+
+    #[test]
+    fn test_is_subset_of_ignores_multiplicity() {
+        let slice = [1, 1, 1, 2, 2];
+        assert!(slice.is_subset_of(&[2, 1]));
+        assert!([1, 2].is_subset_of(&slice));
+    }
+
+    #[test]
+    fn test_is_subset_of_missing_element() {
+        let slice = ["a", "b", "c"];
+        assert!(!slice.is_subset_of(&["a", "b", "b", "a"]));
+    }
+
+    #[test]
+    fn test_is_subset_of_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!(slice.is_subset_of(&[1]));
+        assert!(slice.is_subset_of(&slice));
+        assert!(![1].is_subset_of(&slice));
+    }
+
+    #[test]
+    fn test_is_superset_of_ignores_multiplicity() {
+        let slice = [3, 1, 2];
+        assert!(slice.is_superset_of(&[1, 1, 3, 3, 3]));
+        assert!([1, 1].is_superset_of(&[1, 1, 1, 1]));
+    }
+
+    #[test]
+    fn test_is_superset_of_missing_element() {
+        let slice = [1, 2, 2];
+        assert!(!slice.is_superset_of(&[2, 3]));
+    }
+
+    #[test]
+    fn test_is_superset_of_empty_slice() {
+        let slice: [i32; 0] = [];
+        assert!([1].is_superset_of(&slice));
+        assert!(!slice.is_superset_of(&[1]));
     }
 }
@@ -1,4 +1,6 @@
 pub mod graceful;
+/// Extensions to [`tokio::task::JoinSet`].
+pub mod join_set;
 /// Extensions to [`tokio_util::task::TaskTracker`].
 #[cfg(feature = "task_tracker")]
 pub mod task_tracker;
@@ -7,8 +9,10 @@ use derive_more::Display;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::num::NonZeroU64;
+use tokio::task::JoinHandle;
 
 pub use graceful::GracefulTask;
+pub use join_set::JoinSetExt;
 
 /// A [`TaskId`] that can be `serde`.
 ///
@@ -17,12 +21,46 @@ pub use graceful::GracefulTask;
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct TaskId(pub NonZeroU64);
 
+impl TaskId {
+    /// Returns the id as a primitive `u64`.
+    pub fn get(&self) -> u64 {
+        self.0.get()
+    }
+}
+
 impl From<tokio::task::Id> for TaskId {
     fn from(value: tokio::task::Id) -> Self {
         Self(value.to_string().parse().expect("Should always success"))
     }
 }
 
+/// Spawn a new named asynchronous task, so that it can be identified in tools such as
+/// `tokio-console`.
+///
+/// Naming tasks requires the `tracing` feature flag and building with
+/// `RUSTFLAGS="--cfg tokio_unstable"` (see: [`tokio::task::Builder`]). Otherwise, this falls back
+/// to [`tokio::spawn`] and the task is spawned unnamed.
+///
+/// The [`TaskId`] of the spawned task can be obtained from the returned handle through
+/// [`JoinHandle::id`].
+pub fn spawn_named<F>(name: &str, future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    #[cfg(all(tokio_unstable, feature = "tracing"))]
+    return tokio::task::Builder::new()
+        .name(name)
+        .spawn(future)
+        .expect("Failed to spawn a named task");
+
+    #[cfg(not(all(tokio_unstable, feature = "tracing")))]
+    {
+        let _ = name;
+        tokio::spawn(future)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -32,4 +70,22 @@ mod tests {
         let id = tokio::spawn(async { tokio::task::id() }).await.unwrap();
         assert_eq!(id.to_string(), TaskId::from(id).to_string());
     }
+
+    #[tokio::test]
+    async fn spawn_named() {
+        let handle = super::spawn_named("answer", async { 42 });
+        assert_eq!(handle.await.unwrap(), 42);
+
+        let handle = super::spawn_named("id", async { tokio::task::id() });
+        assert_eq!(TaskId::from(handle.id()), handle.await.unwrap().into());
+    }
+
+    #[tokio::test]
+    async fn get() {
+        let id = tokio::spawn(async { tokio::task::id() }).await.unwrap();
+        assert_eq!(
+            TaskId::from(id).get(),
+            id.to_string().parse::<u64>().unwrap()
+        );
+    }
 }
@@ -16,38 +16,57 @@ use crate::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::{
+    any::Any,
     marker::PhantomData,
     pin::Pin,
-    sync::{Arc, Mutex},
+    sync::{Arc, Mutex, TryLockError, Weak},
     task::{Context, Poll},
+    time::Duration,
 };
+use thiserror::Error;
 use tokio::{
     sync::watch::{Receiver, channel},
     task::{JoinError, JoinHandle},
+    time::Instant,
 };
 
 #[cfg(feature = "signal")]
 use tokio::signal::ctrl_c;
+#[cfg(feature = "cancellation_token")]
+use tokio_util::sync::CancellationToken;
 
-/// Trigger kind of graceful shutdown (triggered by `ctrl-c` or explicit call).
+/// Trigger kind of graceful shutdown (triggered by an OS signal or explicit call).
+///
+/// More kinds may be added as more OS signals are supported, so this enum is
+/// `#[non_exhaustive]`.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
 pub enum GracefulKind {
     /// Triggered by `ctrl-c` signal.
     CtrlC,
+    /// Triggered by `SIGTERM` signal (Unix only).
+    Terminate,
     /// Triggered by explicit call.
     Explicit,
 }
 
-/// Finish mode of the task (whether it is completed completely and exited, or terminated by
-/// graceful shutdown).
+/// Finish mode of the task (whether it is completed completely and exited, terminated by
+/// graceful shutdown, or aborted because graceful shutdown timed out).
+///
+/// This enum is `#[non_exhaustive]`, so that more finish modes can be added without breaking
+/// downstream `match`es.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
 pub enum FinishMode {
     /// Task completed completely and exited.
     Complete,
     /// Task terminated by graceful shutdown.
     Shutdown(GracefulKind),
+    /// Task aborted because it did not exit in time after graceful shutdown (see
+    /// [`GracefulTask::graceful_shutdown_timeout`]).
+    Aborted(GracefulKind),
 }
 
 /// Output of the task.
@@ -60,6 +79,72 @@ pub struct TaskOutput<T> {
     /// The meaning of this field is similar to that of the `Output` of
     /// [`tokio::task::JoinHandle`].
     pub join_result: Result<T, JoinError>,
+    elapsed: Duration,
+}
+
+impl<T> TaskOutput<T> {
+    /// Get the time elapsed from spawning the task to its finish.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Check whether the inner task completed on its own and returned, without graceful shutdown.
+    pub fn finished_actively(&self) -> bool {
+        self.finish_mode == FinishMode::Complete && self.join_result.is_ok()
+    }
+
+    /// Check whether the inner task was stopped passively, that is, it exited because of graceful
+    /// shutdown, or it was aborted or cancelled.
+    ///
+    /// A panicking task is not considered cancelled (see [`panicked`]).
+    ///
+    /// [`panicked`]: TaskOutput::panicked
+    pub fn was_cancelled(&self) -> bool {
+        match &self.join_result {
+            Ok(_) => self.finish_mode != FinishMode::Complete,
+            Err(err) => err.is_cancelled(),
+        }
+    }
+
+    /// Check whether the inner task panicked.
+    pub fn panicked(&self) -> bool {
+        matches!(&self.join_result, Err(err) if err.is_panic())
+    }
+
+    /// Collapse [`finish_mode`] and [`join_result`] into a single [`Result`].
+    ///
+    /// Returns `Ok` with the output of the inner task as long as it returned, regardless of
+    /// whether it completed on its own or exited because of graceful shutdown (check
+    /// [`finish_mode`] first if you need to tell them apart). Otherwise, returns a
+    /// [`TaskFailure`] describing why the inner task did not return.
+    ///
+    /// [`finish_mode`]: TaskOutput::finish_mode
+    /// [`join_result`]: TaskOutput::join_result
+    pub fn into_result(self) -> Result<T, TaskFailure> {
+        let aborted = matches!(self.finish_mode, FinishMode::Aborted(_));
+
+        self.join_result.map_err(|err| match err.try_into_panic() {
+            Ok(payload) => TaskFailure::Panicked(payload),
+            Err(_) if aborted => TaskFailure::Aborted,
+            Err(_) => TaskFailure::Cancelled,
+        })
+    }
+}
+
+/// Error returned by [`TaskOutput::into_result`].
+#[derive(Error, Debug)]
+pub enum TaskFailure {
+    #[error("into_result: the task panicked")]
+    /// The inner task panicked, carrying the panic payload (which can be passed to
+    /// [`std::panic::resume_unwind`]).
+    Panicked(Box<dyn Any + Send + 'static>),
+    #[error("into_result: the task was cancelled")]
+    /// The inner task was cancelled (for example, because the runtime is shutting down).
+    Cancelled,
+    #[error("into_result: the task was aborted after graceful shutdown timed out")]
+    /// The inner task was aborted because it did not exit in time after graceful shutdown (see
+    /// [`GracefulTask::graceful_shutdown_timeout`]).
+    Aborted,
 }
 
 /// Trigger handle for graceful shutdown (can be `Clone`d).
@@ -72,15 +157,89 @@ impl ShutdownTrigger {
     /// Returns true if triggered successfully. If it has been triggered previously (including
     /// triggered by `ctrl-c`), or the task has been completed (whether naturally completed or
     /// terminated), false is returned.
+    ///
+    /// Note that false is also returned if another clone is triggering at the same time, or if
+    /// the internal lock has been poisoned, use [`try_trigger`] to tell these cases apart.
+    ///
+    /// [`try_trigger`]: ShutdownTrigger::try_trigger
     pub fn trigger(&self) -> bool {
-        match self.0.try_lock() {
-            Err(_) => false,
-            Ok(mut guard) => match guard.take() {
-                None => false,
-                Some(trigger) => trigger.trigger(),
+        self.try_trigger() == TriggerResult::Triggered
+    }
+
+    /// Trigger the graceful shutdown, and report the outcome in detail.
+    ///
+    /// See [`TriggerResult`] for the possible outcomes.
+    pub fn try_trigger(&self) -> TriggerResult {
+        let mut guard = match self.0.try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::Poisoned(_)) => return TriggerResult::Poisoned,
+            Err(TryLockError::WouldBlock) => return TriggerResult::Contended,
+        };
+
+        match guard.as_ref() {
+            None => TriggerResult::AlreadyTriggered,
+            Some(trigger) if trigger.is_dropped() => TriggerResult::Finished,
+            Some(_) => match guard.take().map(OnceTrigger::trigger) {
+                Some(true) => TriggerResult::Triggered,
+                _ => TriggerResult::Finished,
             },
         }
     }
+
+    /// Check whether the graceful shutdown has been (or is being) triggered.
+    fn is_triggered(&self) -> bool {
+        match self.0.try_lock() {
+            Ok(guard) => guard.is_none(),
+            Err(TryLockError::Poisoned(err)) => err.into_inner().is_none(),
+            Err(TryLockError::WouldBlock) => true,
+        }
+    }
+
+    /// Create a [`WeakShutdownTrigger`] pointing to the same trigger, without keeping it alive.
+    pub fn downgrade(&self) -> WeakShutdownTrigger {
+        WeakShutdownTrigger(Arc::downgrade(&self.0))
+    }
+}
+
+/// Outcome of [`ShutdownTrigger::try_trigger`].
+///
+/// This enum is `#[non_exhaustive]`, so that more outcomes can be added without breaking
+/// downstream `match`es.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum TriggerResult {
+    /// The graceful shutdown was triggered successfully.
+    Triggered,
+    /// The graceful shutdown has been triggered previously (including triggered by OS signals).
+    AlreadyTriggered,
+    /// Another clone of the [`ShutdownTrigger`] is triggering at the same time, retrying later
+    /// will get the actual outcome.
+    Contended,
+    /// The task has been completed (whether naturally completed or terminated), so there is
+    /// nothing to trigger.
+    Finished,
+    /// The internal lock was poisoned by a thread that panicked while holding it, so nothing was
+    /// triggered.
+    Poisoned,
+}
+
+/// Weak version of [`ShutdownTrigger`] (can be `Clone`d).
+///
+/// This handle does not keep the trigger alive, which is useful for registries that need to
+/// reference many tasks without owning them. Use [`ShutdownTrigger::downgrade`] to construct.
+#[derive(Debug, Clone)]
+pub struct WeakShutdownTrigger(Weak<Mutex<Option<OnceTrigger>>>);
+
+impl WeakShutdownTrigger {
+    /// Attempt to upgrade to a [`ShutdownTrigger`].
+    ///
+    /// Returns `None` if all the [`ShutdownTrigger`]s have been dropped, which happens once the
+    /// task has finished, and its [`GracefulTask`] along with all handles obtained from
+    /// [`GracefulTask::shutdown_handle`] have been dropped.
+    pub fn upgrade(&self) -> Option<ShutdownTrigger> {
+        self.0.upgrade().map(ShutdownTrigger)
+    }
 }
 
 /// Receiver handle for graceful shutdown (can be `Clone`d).
@@ -103,7 +262,11 @@ impl ShutdownTrigger {
 /// to all receivers without loss or delay of the signal. This is useful when subtasks are spawned
 /// in the `Future` you provide.
 #[derive(Debug, Clone)]
-pub struct ShutdownReceiver(RecvInner);
+pub struct ShutdownReceiver {
+    inner: RecvInner,
+    #[cfg(feature = "cancellation_token")]
+    child_token: Option<CancellationToken>,
+}
 
 #[derive(Debug, Clone)]
 enum RecvInner {
@@ -114,7 +277,7 @@ enum RecvInner {
 impl ShutdownReceiver {
     /// Await and receive the graceful shutdown signal.
     pub async fn recv(&mut self) -> GracefulKind {
-        match &mut self.0 {
+        match &mut self.inner {
             RecvInner::Pending(receiver) => {
                 let init = *receiver.borrow_and_update();
                 let kind = match init {
@@ -129,12 +292,71 @@ impl ShutdownReceiver {
                     }
                 };
 
-                self.0 = RecvInner::Shutdown(kind);
+                self.inner = RecvInner::Shutdown(kind);
                 kind
             }
             RecvInner::Shutdown(kind) => *kind,
         }
     }
+
+    /// Await the graceful shutdown signal, and project its kind with `f`.
+    ///
+    /// This is useful for translating the shutdown kind into a domain-specific action inline.
+    pub async fn map<U, F>(mut self, f: F) -> U
+    where
+        F: FnOnce(GracefulKind) -> U,
+    {
+        f(self.recv().await)
+    }
+
+    /// Convert into a [`CancellationToken`] that will be cancelled when the graceful shutdown
+    /// signal is received.
+    ///
+    /// This bridges graceful shutdown to libraries that expect a [`CancellationToken`]. A small
+    /// task is spawned to wait for the signal, so this must be called within a Tokio runtime. If
+    /// the task finishes without graceful shutdown, the token will never be cancelled.
+    #[cfg(feature = "cancellation_token")]
+    pub fn into_cancellation_token(mut self) -> CancellationToken {
+        let token = CancellationToken::new();
+        let cancel = token.clone();
+
+        tokio::spawn(async move {
+            if self.recv_unless_finished().await.is_some() {
+                cancel.cancel();
+            }
+        });
+
+        token
+    }
+
+    /// Returns the child [`CancellationToken`] enabled by [`with_child_token`], which will be
+    /// cancelled when graceful shutdown is triggered, or `None` if it is not enabled.
+    ///
+    /// Clones of the token (or its [`child_token`]s) can be passed to the subtasks spawned in the
+    /// `Future`, so that they can observe the shutdown without a [`ShutdownReceiver`].
+    ///
+    /// [`with_child_token`]: GracefulTaskBuilder::with_child_token
+    /// [`child_token`]: CancellationToken::child_token
+    #[cfg(feature = "cancellation_token")]
+    pub fn child_token(&self) -> Option<&CancellationToken> {
+        self.child_token.as_ref()
+    }
+
+    /// Similar to [`recv`], but resolves to `None` (instead of panicking) if the task has finished
+    /// without graceful shutdown, which can happen when this receiver outlives the task.
+    ///
+    /// [`recv`]: ShutdownReceiver::recv
+    #[cfg(feature = "cancellation_token")]
+    async fn recv_unless_finished(&mut self) -> Option<GracefulKind> {
+        match &mut self.inner {
+            RecvInner::Pending(receiver) => {
+                let kind = (*receiver.wait_for(Option::is_some).await.ok()?)?;
+                self.inner = RecvInner::Shutdown(kind);
+                Some(kind)
+            }
+            RecvInner::Shutdown(kind) => Some(*kind),
+        }
+    }
 }
 
 impl IntoFuture for ShutdownReceiver {
@@ -146,13 +368,19 @@ impl IntoFuture for ShutdownReceiver {
     }
 }
 
-/// The builder of [`GracefulTask`] (currently only affects whether to respond to `ctrl-c`
-/// signals).
+/// The builder of [`GracefulTask`] (affects whether to respond to OS signals, whether to restart
+/// on panic, and the name of the spawned tasks).
 ///
-/// The default value is not to respond to the `ctrl-c` signal.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+/// The default value is not to respond to any OS signal, not to restart, and not to name the
+/// tasks.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct GracefulTaskBuilder<T> {
     ctrlc_shutdown: bool,
+    terminate_shutdown: bool,
+    max_restarts: usize,
+    #[cfg(feature = "cancellation_token")]
+    child_token: bool,
+    name: Option<Arc<str>>,
     _phantom: PhantomData<T>,
 }
 
@@ -160,12 +388,31 @@ impl<T> Default for GracefulTaskBuilder<T> {
     fn default() -> Self {
         Self {
             ctrlc_shutdown: false,
+            terminate_shutdown: false,
+            max_restarts: 0,
+            #[cfg(feature = "cancellation_token")]
+            child_token: false,
+            name: None,
             _phantom: PhantomData,
         }
     }
 }
 
 impl<T> GracefulTaskBuilder<T> {
+    /// Name the spawned tasks, so that they can be identified in tools such as `tokio-console`.
+    ///
+    /// The inner task is named `name`, and the outer task is named `"{name} (graceful)"`.
+    ///
+    /// Naming tasks requires the `tracing` feature flag and building with
+    /// `RUSTFLAGS="--cfg tokio_unstable"` (see: [`tokio::task::Builder`]). Otherwise the tasks are
+    /// spawned unnamed, but the name can still be obtained through [`GracefulTask::name`].
+    pub fn name(self, name: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into().into()),
+            ..self
+        }
+    }
+
     /// Enable `ctrl-c` shutdown.
     #[cfg(feature = "signal")]
     pub fn ctrlc_shutdown(self) -> Self {
@@ -175,6 +422,58 @@ impl<T> GracefulTaskBuilder<T> {
         }
     }
 
+    /// Enable shutdown on the common set of OS signals used to stop a process.
+    ///
+    /// On Unix, this responds to both `ctrl-c` (`SIGINT`) and `SIGTERM` (which is what service
+    /// managers and container runtimes send), reported as [`GracefulKind::CtrlC`] and
+    /// [`GracefulKind::Terminate`] respectively.
+    ///
+    /// On Windows there is no `SIGTERM`, so this falls back to `ctrl-c` only, and is equivalent
+    /// to [`ctrlc_shutdown`].
+    ///
+    /// [`ctrlc_shutdown`]: GracefulTaskBuilder::ctrlc_shutdown
+    #[cfg(feature = "signal")]
+    pub fn any_os_signal(self) -> Self {
+        Self {
+            ctrlc_shutdown: true,
+            terminate_shutdown: cfg!(unix),
+            ..self
+        }
+    }
+
+    /// Create a [`CancellationToken`] for the task, which will be cancelled when graceful shutdown
+    /// is triggered.
+    ///
+    /// The token can be obtained through [`ShutdownReceiver::child_token`], and passed to the
+    /// subtasks spawned in the `Future`, so that the shutdown propagates to them hierarchically.
+    #[cfg(feature = "cancellation_token")]
+    pub fn with_child_token(self) -> Self {
+        Self {
+            child_token: true,
+            ..self
+        }
+    }
+
+    /// Restart the inner task if it panics, up to `max_restarts` times.
+    ///
+    /// Restarting requires a way to create the `Future` again, so this only takes effect for tasks
+    /// spawned by [`spawn_factory`]. When the inner task panics and the restart budget is not yet
+    /// exhausted, a new inner task is spawned from the factory (with a new [`ShutdownReceiver`]).
+    /// Once the budget is exhausted, the panic is reported in [`TaskOutput::join_result`] as
+    /// usual.
+    ///
+    /// Tasks are never restarted after graceful shutdown has been triggered.
+    ///
+    /// Note that [`GracefulTask::ids`] always returns the ID of the first spawned inner task.
+    ///
+    /// [`spawn_factory`]: GracefulTaskBuilder::spawn_factory
+    pub fn restart_on_panic(self, max_restarts: usize) -> Self {
+        Self {
+            max_restarts,
+            ..self
+        }
+    }
+
     /// Spawn an asynchronous task that can be gracefully shutdown.
     ///
     /// The parameter `ifwa` can be a closure that returns `Future`, an async closure, an async
@@ -195,51 +494,199 @@ impl<T> GracefulTaskBuilder<T> {
         F: Future<Output = T> + Send + 'static,
         T: Send + 'static,
     {
-        self.spawn_ctrlc_mocked(ifwa, async move {
-            #[cfg(feature = "signal")]
-            ctrl_c().await.ok();
-        })
+        self.spawn_signal_mocked(ifwa, ctrlc_signal(), terminate_signal())
+    }
+
+    /// Spawn an asynchronous task that can be gracefully shutdown, forwarding an extra argument
+    /// to it.
+    ///
+    /// This behaves like [`spawn`], except that `ifwa` receives a tuple of the
+    /// [`ShutdownReceiver`] and `arg`, so configuration or shared state can be passed in without
+    /// capturing it in a closure.
+    ///
+    /// [`spawn`]: GracefulTaskBuilder::spawn
+    pub fn spawn_with<A, I, F>(self, arg: A, ifwa: I) -> GracefulTask<T>
+    where
+        I: IntoFutureWithArgs<(ShutdownReceiver, A), F>,
+        F: Future<Output = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        self.spawn(move |shutdown| ifwa.into_future_with_args((shutdown, arg)))
     }
 
+    /// Spawn an asynchronous task that can be gracefully shutdown, creating the `Future` from a
+    /// `factory` that can be called multiple times.
+    ///
+    /// This behaves like [`spawn`], except that the inner task can be restarted from the
+    /// `factory` if it panics (see [`restart_on_panic`]).
+    ///
+    /// [`spawn`]: GracefulTaskBuilder::spawn
+    /// [`restart_on_panic`]: GracefulTaskBuilder::restart_on_panic
+    pub fn spawn_factory<R, F>(self, mut factory: R) -> GracefulTask<T>
+    where
+        R: FnMut(ShutdownReceiver) -> F + Send + 'static,
+        F: Future<Output = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        self.spawn_supervised(
+            move |shutdown| (factory(shutdown), Some(factory)),
+            ctrlc_signal(),
+            terminate_signal(),
+        )
+    }
+
+    #[cfg(test)]
     fn spawn_ctrlc_mocked<I, F, C>(self, ifwa: I, ctrlc: C) -> GracefulTask<T>
     where
         I: IntoFutureWithArgs<ShutdownReceiver, F>,
         F: Future<Output = T> + Send + 'static,
         C: Future<Output = ()> + Send + 'static,
         T: Send + 'static,
+    {
+        self.spawn_signal_mocked(ifwa, ctrlc, std::future::pending())
+    }
+
+    fn spawn_signal_mocked<I, F, C, S>(self, ifwa: I, ctrlc: C, terminate: S) -> GracefulTask<T>
+    where
+        I: IntoFutureWithArgs<ShutdownReceiver, F>,
+        F: Future<Output = T> + Send + 'static,
+        C: Future<Output = ()> + Send + 'static,
+        S: Future<Output = ()> + Send + 'static,
+        T: Send + 'static,
+    {
+        self.spawn_supervised(
+            move |shutdown| {
+                let future = ifwa.into_future_with_args(shutdown);
+                (future, None::<fn(ShutdownReceiver) -> F>)
+            },
+            ctrlc,
+            terminate,
+        )
+    }
+
+    /// `init` creates the first `Future`, and optionally returns a factory used for restarts.
+    fn spawn_supervised<M, R, F, C, S>(self, init: M, ctrlc: C, terminate: S) -> GracefulTask<T>
+    where
+        M: FnOnce(ShutdownReceiver) -> (F, Option<R>),
+        R: FnMut(ShutdownReceiver) -> F + Send + 'static,
+        F: Future<Output = T> + Send + 'static,
+        C: Future<Output = ()> + Send + 'static,
+        S: Future<Output = ()> + Send + 'static,
+        T: Send + 'static,
     {
         let ctrlc_shutdown = self.ctrlc_shutdown;
+        let terminate_shutdown = self.terminate_shutdown;
+        let max_restarts = self.max_restarts;
         let ctrlc = if ctrlc_shutdown { Some(ctrlc) } else { None };
+        let terminate = if terminate_shutdown {
+            Some(terminate)
+        } else {
+            None
+        };
         let (sender, recver) = channel(None);
-        let (trigger, waiter) = once_event();
+        let (trigger, mut waiter) = once_event();
         let trigger = ShutdownTrigger(Arc::new(Mutex::new(Some(trigger))));
-        let mut inner_task =
-            tokio::spawn(ifwa.into_future_with_args(ShutdownReceiver(RecvInner::Pending(recver))));
+        let (abort, mut abort_waiter) = once_event();
+        let started = Instant::now();
+        let name = self.name;
+        #[cfg(feature = "cancellation_token")]
+        let child_token = self.child_token.then(CancellationToken::new);
+        #[cfg(feature = "cancellation_token")]
+        let cancel = child_token.clone();
+        let receiver = move || ShutdownReceiver {
+            inner: RecvInner::Pending(recver.clone()),
+            #[cfg(feature = "cancellation_token")]
+            child_token: child_token.clone(),
+        };
+        let (future, mut restart) = init(receiver());
+        let mut inner_task = spawn(name.as_deref(), future);
 
         let inner = inner_task.id().into();
         let graceful = trigger.clone();
-        let task = tokio::spawn(async move {
-            // This async block will only be polled when `ctrlc` is `Some`, so the unwrap here is
-            // safe.
+        let outer_name = name.as_ref().map(|name| format!("{} (graceful)", name));
+        let inner_name = name.clone();
+        let task = spawn(outer_name.as_deref(), async move {
+            // These async blocks will only be polled when `ctrlc` / `terminate` are `Some`, so the
+            // unwraps here are safe.
             let ctrlc = async move { ctrlc.unwrap().await };
-            let (finish_mode, join_result) = tokio::select! {
-                _ = ctrlc, if ctrlc_shutdown => {
-                    trigger.trigger();
-                    let kind = GracefulKind::CtrlC;
-                    sender.send(Some(kind)).ok();
-                    (FinishMode::Shutdown(kind), inner_task.await)
-                },
-                _ = waiter => {
-                    let kind = GracefulKind::Explicit;
-                    sender.send(Some(kind)).ok();
-                    (FinishMode::Shutdown(kind), inner_task.await)
-                },
-                join_result = &mut inner_task => (FinishMode::Complete, join_result),
+            let terminate = async move { terminate.unwrap().await };
+            tokio::pin!(ctrlc, terminate);
+
+            let mut restarts = 0;
+            // The join result is `Some` if the inner task has already panicked when graceful
+            // shutdown is found to be triggered instead of restarting it.
+            let (kind, panicked) = loop {
+                // Biased so that the shutdown branches win over a panic that is ready in the same
+                // poll, otherwise the task could be restarted after shutdown was triggered.
+                tokio::select! {
+                    biased;
+                    _ = &mut ctrlc, if ctrlc_shutdown => {
+                        trigger.trigger();
+                        break (GracefulKind::CtrlC, None);
+                    },
+                    _ = &mut terminate, if terminate_shutdown => {
+                        trigger.trigger();
+                        break (GracefulKind::Terminate, None);
+                    },
+                    _ = &mut waiter => break (GracefulKind::Explicit, None),
+                    join_result = &mut inner_task => match (join_result, restart.as_mut()) {
+                        // Triggered after `waiter` was polled but before the panic was observed.
+                        (Err(err), Some(_)) if err.is_panic() && trigger.is_triggered() => {
+                            break (GracefulKind::Explicit, Some(Err(err)));
+                        }
+                        (Err(err), Some(restart))
+                            if err.is_panic() && restarts < max_restarts =>
+                        {
+                            restarts += 1;
+                            inner_task = spawn(inner_name.as_deref(), restart(receiver()));
+                        }
+                        (join_result, _) => {
+                            return TaskOutput {
+                                finish_mode: FinishMode::Complete,
+                                join_result,
+                                elapsed: started.elapsed(),
+                            };
+                        }
+                    },
+                }
             };
 
-            TaskOutput {
-                finish_mode,
-                join_result,
+            sender.send(Some(kind)).ok();
+            #[cfg(feature = "cancellation_token")]
+            if let Some(cancel) = cancel {
+                cancel.cancel();
+            }
+
+            if let Some(join_result) = panicked {
+                return TaskOutput {
+                    finish_mode: FinishMode::Shutdown(kind),
+                    join_result,
+                    elapsed: started.elapsed(),
+                };
+            }
+
+            tokio::select! {
+                join_result = &mut inner_task => TaskOutput {
+                    finish_mode: FinishMode::Shutdown(kind),
+                    join_result,
+                    elapsed: started.elapsed(),
+                },
+                // The `abort` trigger is dropped without triggering when the `GracefulTask` is
+                // dropped, so only abort when it is actually triggered.
+                true = &mut abort_waiter => {
+                    inner_task.abort();
+                    let join_result = inner_task.await;
+                    let finish_mode = match &join_result {
+                        Err(err) if err.is_cancelled() => FinishMode::Aborted(kind),
+                        _ => FinishMode::Shutdown(kind),
+                    };
+
+                    TaskOutput {
+                        finish_mode,
+                        join_result,
+                        elapsed: started.elapsed(),
+                    }
+                },
             }
         });
         let outer = task.id().into();
@@ -247,12 +694,46 @@ impl<T> GracefulTaskBuilder<T> {
         GracefulTask {
             inner,
             outer,
+            name,
             graceful,
+            abort: Some(abort),
+            started,
             task,
         }
     }
 }
 
+fn spawn<F>(name: Option<&str>, future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    match name {
+        Some(name) => super::spawn_named(name, future),
+        None => tokio::spawn(future),
+    }
+}
+
+async fn ctrlc_signal() {
+    #[cfg(feature = "signal")]
+    ctrl_c().await.ok();
+}
+
+async fn terminate_signal() {
+    #[cfg(all(feature = "signal", unix))]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        // If the handler cannot be registered, never resolve instead of shutting down immediately.
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                sigterm.recv().await;
+            }
+            Err(_) => std::future::pending().await,
+        }
+    }
+}
+
 /// The handle and entry type of asynchronous tasks that can be shutdown gracefully.
 ///
 /// This type is very similar to [`tokio::task::JoinHandle`], but the difference is that it can
@@ -324,7 +805,10 @@ impl<T> GracefulTaskBuilder<T> {
 pub struct GracefulTask<T> {
     inner: TaskId,
     outer: TaskId,
+    name: Option<Arc<str>>,
     graceful: ShutdownTrigger,
+    abort: Option<OnceTrigger>,
+    started: Instant,
     task: JoinHandle<TaskOutput<T>>,
 }
 
@@ -344,6 +828,11 @@ impl<T> GracefulTask<T> {
         (self.outer, self.inner)
     }
 
+    /// Get the name set by [`GracefulTaskBuilder::name`], if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
     /// Trigger the graceful shutdown.
     ///
     /// Returns true if triggered successfully. If it has been triggered previously (including
@@ -360,11 +849,46 @@ impl<T> GracefulTask<T> {
         self.await
     }
 
+    /// Trigger graceful shutdown, and await for the task to complete within `timeout` and obtain
+    /// the [`TaskOutput`].
+    ///
+    /// If the inner task does not exit within `timeout`, it will be aborted, and the
+    /// [`TaskOutput::finish_mode`] will be [`FinishMode::Aborted`].
+    pub async fn graceful_shutdown_timeout(mut self, timeout: Duration) -> TaskOutput<T> {
+        self.trigger_graceful_shutdown();
+
+        if let Ok(task_output) = tokio::time::timeout(timeout, &mut self).await {
+            return task_output;
+        }
+
+        if let Some(abort) = self.abort.take() {
+            abort.trigger();
+        }
+
+        self.await
+    }
+
     /// Returns a new [`ShutdownTrigger`] that can be used to remotely graceful shutdown this task.
     pub fn shutdown_handle(&self) -> ShutdownTrigger {
         self.graceful.clone()
     }
 
+    /// Detach the task, letting it run to completion in the background without awaiting it.
+    ///
+    /// The [`TaskOutput`] will be discarded, but the returned [`ShutdownTrigger`] can still be used
+    /// to gracefully shutdown the task.
+    pub fn detach(self) -> ShutdownTrigger {
+        self.graceful
+    }
+
+    /// Returns the time elapsed since the task was spawned.
+    ///
+    /// This keeps growing after the task has finished, see [`TaskOutput::elapsed`] for the
+    /// lifetime of a finished task.
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
     /// Checks if the tasks associated with this `GracefulTask` have finished.
     pub fn is_finished(&self) -> bool {
         self.task.is_finished()
@@ -413,6 +937,41 @@ mod tests {
         assert_eq!(task_output.join_result.unwrap(), GracefulKind::Explicit);
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn graceful_shutdown_timeout() {
+        let timeout = Duration::from_millis(100);
+
+        let task_output = GracefulTask::builder_default()
+            .spawn(async |shutdown| shutdown.await)
+            .graceful_shutdown_timeout(timeout)
+            .await;
+        assert_eq!(
+            task_output.finish_mode,
+            FinishMode::Shutdown(GracefulKind::Explicit)
+        );
+        assert_eq!(task_output.join_result.unwrap(), GracefulKind::Explicit);
+
+        let graceful_task = GracefulTask::builder_default().spawn(async |_| 42);
+        sleep().await;
+        let task_output = graceful_task.graceful_shutdown_timeout(timeout).await;
+        assert_eq!(task_output.finish_mode, FinishMode::Complete);
+        assert_eq!(task_output.join_result.unwrap(), 42);
+
+        let task_output = GracefulTask::builder_default()
+            .spawn(async |_| tokio::time::sleep(Duration::from_secs(10)).await)
+            .graceful_shutdown_timeout(timeout)
+            .await;
+        assert_eq!(
+            task_output.finish_mode,
+            FinishMode::Aborted(GracefulKind::Explicit)
+        );
+        assert!(task_output.join_result.as_ref().unwrap_err().is_cancelled());
+        assert!(matches!(
+            task_output.into_result(),
+            Err(TaskFailure::Aborted)
+        ));
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn trigger_graceful_shutdown() {
         let mut graceful_task =
@@ -512,6 +1071,456 @@ mod tests {
         assert_eq!(task_output.join_result.unwrap(), ());
     }
 
+    #[test]
+    #[cfg(feature = "signal")]
+    fn any_os_signal() {
+        let builder = GracefulTask::<()>::builder_default();
+        assert!(!builder.ctrlc_shutdown);
+        assert!(!builder.terminate_shutdown);
+
+        let builder = builder.any_os_signal();
+        assert!(builder.ctrlc_shutdown);
+        assert_eq!(builder.terminate_shutdown, cfg!(unix));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(all(feature = "signal", unix))]
+    async fn any_os_signal_mocked() {
+        let (ctrlc_trigger, ctrlc) = ctrlc_mocked();
+        let (terminate_trigger, terminate) = ctrlc_mocked();
+        let mut graceful_task = GracefulTask::builder_default()
+            .any_os_signal()
+            .spawn_signal_mocked(async |shutdown| shutdown.await, ctrlc, terminate);
+        assert!(!graceful_task.is_finished());
+        assert!(terminate_trigger.trigger());
+        sleep().await;
+        assert!(!graceful_task.trigger_graceful_shutdown());
+        assert!(!ctrlc_trigger.trigger());
+        let task_output = (&mut graceful_task).await;
+        assert!(graceful_task.is_finished());
+        assert_eq!(
+            task_output.finish_mode,
+            FinishMode::Shutdown(GracefulKind::Terminate)
+        );
+        assert_eq!(task_output.join_result.unwrap(), GracefulKind::Terminate);
+
+        let (ctrlc_trigger, ctrlc) = ctrlc_mocked();
+        let (terminate_trigger, terminate) = ctrlc_mocked();
+        let mut graceful_task = GracefulTask::builder_default()
+            .any_os_signal()
+            .spawn_signal_mocked(async |shutdown| shutdown.await, ctrlc, terminate);
+        assert!(!graceful_task.is_finished());
+        assert!(ctrlc_trigger.trigger());
+        sleep().await;
+        assert!(!graceful_task.trigger_graceful_shutdown());
+        assert!(!terminate_trigger.trigger());
+        let task_output = (&mut graceful_task).await;
+        assert!(graceful_task.is_finished());
+        assert_eq!(
+            task_output.finish_mode,
+            FinishMode::Shutdown(GracefulKind::CtrlC)
+        );
+        assert_eq!(task_output.join_result.unwrap(), GracefulKind::CtrlC);
+
+        let (_ctrlc_trigger, ctrlc) = ctrlc_mocked();
+        let (terminate_trigger, terminate) = ctrlc_mocked();
+        let mut graceful_task = GracefulTask::builder_default()
+            .ctrlc_shutdown()
+            .spawn_signal_mocked(async |shutdown| shutdown.await, ctrlc, terminate);
+        assert!(!graceful_task.is_finished());
+        assert!(!terminate_trigger.trigger());
+        sleep().await;
+        assert!(graceful_task.trigger_graceful_shutdown());
+        let task_output = (&mut graceful_task).await;
+        assert!(graceful_task.is_finished());
+        assert_eq!(
+            task_output.finish_mode,
+            FinishMode::Shutdown(GracefulKind::Explicit)
+        );
+        assert_eq!(task_output.join_result.unwrap(), GracefulKind::Explicit);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn restart_on_panic() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        async fn flaky(runs: Arc<AtomicUsize>, panics: usize) -> i32 {
+            if runs.fetch_add(1, Ordering::SeqCst) < panics {
+                panic!("flaky");
+            }
+            42
+        }
+
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+        let task_output = GracefulTask::builder_default()
+            .restart_on_panic(3)
+            .spawn_factory(move |_| flaky(counter.clone(), 2))
+            .await;
+        assert_eq!(task_output.finish_mode, FinishMode::Complete);
+        assert_eq!(task_output.join_result.unwrap(), 42);
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+        let task_output = GracefulTask::builder_default()
+            .restart_on_panic(1)
+            .spawn_factory(move |_| flaky(counter.clone(), 2))
+            .await;
+        assert_eq!(task_output.finish_mode, FinishMode::Complete);
+        assert!(task_output.join_result.unwrap_err().is_panic());
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+        let task_output = GracefulTask::builder_default()
+            .spawn_factory(move |_| flaky(counter.clone(), 2))
+            .await;
+        assert!(task_output.join_result.unwrap_err().is_panic());
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        let task_output = GracefulTask::builder_default()
+            .restart_on_panic(3)
+            .spawn_factory(|shutdown| shutdown.into_future())
+            .graceful_shutdown()
+            .await;
+        assert_eq!(
+            task_output.finish_mode,
+            FinishMode::Shutdown(GracefulKind::Explicit)
+        );
+        assert_eq!(task_output.join_result.unwrap(), GracefulKind::Explicit);
+    }
+
+    #[tokio::test]
+    async fn no_restart_after_shutdown() {
+        use std::sync::{
+            OnceLock,
+            atomic::{AtomicUsize, Ordering},
+        };
+
+        // Repeat, since without the fix the outcome depends on the random `select!` branch order.
+        for _ in 0..32 {
+            let runs = Arc::new(AtomicUsize::new(0));
+            let handle = Arc::new(OnceLock::<ShutdownTrigger>::new());
+            let (counter, slot) = (runs.clone(), handle.clone());
+            let graceful_task = GracefulTask::<()>::builder_default()
+                .restart_on_panic(3)
+                .spawn_factory(move |_| {
+                    let (counter, slot) = (counter.clone(), slot.clone());
+                    async move {
+                        counter.fetch_add(1, Ordering::SeqCst);
+
+                        while slot.get().is_none() {
+                            tokio::task::yield_now().await;
+                        }
+
+                        // Trigger and panic in the same tick.
+                        slot.get().unwrap().trigger();
+                        panic!("panic right after shutdown");
+                    }
+                });
+            handle.set(graceful_task.shutdown_handle()).unwrap();
+
+            let task_output = graceful_task.await;
+            assert_eq!(
+                task_output.finish_mode,
+                FinishMode::Shutdown(GracefulKind::Explicit)
+            );
+            assert!(task_output.join_result.unwrap_err().is_panic());
+            assert_eq!(runs.load(Ordering::SeqCst), 1);
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn name() {
+        let graceful_task = GracefulTask::builder_default().spawn(async |_| 42);
+        assert_eq!(graceful_task.name(), None);
+        assert_eq!(graceful_task.await.join_result.unwrap(), 42);
+
+        let id = 7;
+        let builder = GracefulTask::builder_default().name(format!("worker-{id}"));
+        assert_eq!(builder.clone(), builder);
+        let graceful_task = builder.spawn(async |shutdown| shutdown.await);
+        assert_eq!(graceful_task.name(), Some("worker-7"));
+        assert!(!graceful_task.name().unwrap().is_empty());
+        let task_output = graceful_task.graceful_shutdown().await;
+        assert_eq!(task_output.join_result.unwrap(), GracefulKind::Explicit);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn weak_shutdown_trigger() {
+        let graceful_task = GracefulTask::builder_default().spawn(async |shutdown| shutdown.await);
+        let weak = graceful_task.shutdown_handle().downgrade();
+        assert!(weak.upgrade().unwrap().trigger());
+        let task_output = graceful_task.await;
+        assert_eq!(task_output.join_result.unwrap(), GracefulKind::Explicit);
+        assert!(weak.upgrade().is_none());
+
+        let graceful_task = GracefulTask::builder_default().spawn(async |_| 42);
+        let strong = graceful_task.shutdown_handle();
+        let weak = strong.downgrade();
+        assert_eq!(graceful_task.await.join_result.unwrap(), 42);
+        assert!(weak.upgrade().is_some());
+        drop(strong);
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn detach() {
+        let (trigger, waiter) = once_event();
+        let shutdown = GracefulTask::builder_default()
+            .spawn(async move |shutdown: ShutdownReceiver| {
+                shutdown.await;
+                trigger.trigger();
+            })
+            .detach();
+        sleep().await;
+        assert!(shutdown.trigger());
+        assert!(waiter.await);
+        assert!(!shutdown.trigger());
+
+        let (trigger, waiter) = once_event();
+        let shutdown = GracefulTask::builder_default()
+            .spawn(async move |_| {
+                trigger.trigger();
+            })
+            .detach();
+        assert!(waiter.await);
+        sleep().await;
+        assert!(!shutdown.trigger());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn map() {
+        let task_output = GracefulTask::builder_default()
+            .spawn(async |shutdown: ShutdownReceiver| {
+                shutdown.map(|kind| format!("{:?}", kind)).await
+            })
+            .graceful_shutdown()
+            .await;
+        assert_eq!(task_output.join_result.unwrap(), "Explicit");
+
+        #[cfg(feature = "signal")]
+        {
+            let (trigger, ctrlc) = ctrlc_mocked();
+            let graceful_task = GracefulTask::builder_default()
+                .ctrlc_shutdown()
+                .spawn_ctrlc_mocked(
+                    async |shutdown: ShutdownReceiver| {
+                        shutdown.map(|kind| kind == GracefulKind::CtrlC).await
+                    },
+                    ctrlc,
+                );
+            assert!(trigger.trigger());
+            assert!(graceful_task.await.join_result.unwrap());
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(feature = "cancellation_token")]
+    async fn into_cancellation_token() {
+        let (trigger, waiter) = once_event();
+        let graceful_task =
+            GracefulTask::builder_default().spawn(async move |shutdown: ShutdownReceiver| {
+                let token = shutdown.into_cancellation_token();
+                sleep().await;
+                assert!(!token.is_cancelled());
+                trigger.trigger();
+                token.cancelled().await;
+                42
+            });
+        assert!(waiter.await);
+        let task_output = graceful_task.graceful_shutdown().await;
+        assert_eq!(task_output.join_result.unwrap(), 42);
+
+        let token = GracefulTask::builder_default()
+            .spawn(async |shutdown: ShutdownReceiver| shutdown.into_cancellation_token())
+            .await
+            .join_result
+            .unwrap();
+        sleep().await;
+        assert!(!token.is_cancelled());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(feature = "cancellation_token")]
+    async fn with_child_token() {
+        let graceful_task = GracefulTask::builder_default().with_child_token().spawn(
+            async |shutdown: ShutdownReceiver| {
+                let token = shutdown.child_token().unwrap().child_token();
+                let child = tokio::spawn(async move {
+                    token.cancelled().await;
+                    42
+                });
+                shutdown.await;
+                child.await.unwrap()
+            },
+        );
+        sleep().await;
+        let task_output = graceful_task.graceful_shutdown().await;
+        assert_eq!(
+            task_output.finish_mode,
+            FinishMode::Shutdown(GracefulKind::Explicit)
+        );
+        assert_eq!(task_output.join_result.unwrap(), 42);
+
+        let task_output = GracefulTask::builder_default()
+            .spawn(async |shutdown: ShutdownReceiver| shutdown.child_token().is_none())
+            .await;
+        assert!(task_output.join_result.unwrap());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn spawn_with() {
+        async fn sum((shutdown, nums): (ShutdownReceiver, Vec<i32>)) -> i32 {
+            shutdown.await;
+            nums.into_iter().sum()
+        }
+
+        let graceful_task = GracefulTask::builder_default().spawn_with(vec![40, 1, 1], sum);
+        sleep().await;
+        let task_output = graceful_task.graceful_shutdown().await;
+        assert_eq!(
+            task_output.finish_mode,
+            FinishMode::Shutdown(GracefulKind::Explicit)
+        );
+        assert_eq!(task_output.join_result.unwrap(), 42);
+
+        let config = String::from("config");
+        let task_output = GracefulTask::builder_default()
+            .spawn_with(config, async |(_, config): (ShutdownReceiver, String)| {
+                config
+            })
+            .await;
+        assert_eq!(task_output.finish_mode, FinishMode::Complete);
+        assert_eq!(task_output.join_result.unwrap(), "config");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn predicates() {
+        let task_output = GracefulTask::builder_default().spawn(async |_| 42).await;
+        assert!(task_output.finished_actively());
+        assert!(!task_output.was_cancelled());
+        assert!(!task_output.panicked());
+
+        let task_output = GracefulTask::builder_default()
+            .spawn(async |shutdown| shutdown.await)
+            .graceful_shutdown()
+            .await;
+        assert!(!task_output.finished_actively());
+        assert!(task_output.was_cancelled());
+        assert!(!task_output.panicked());
+
+        let task_output = GracefulTask::builder_default()
+            .spawn(async |_| tokio::time::sleep(Duration::from_secs(10)).await)
+            .graceful_shutdown_timeout(Duration::from_millis(100))
+            .await;
+        assert!(!task_output.finished_actively());
+        assert!(task_output.was_cancelled());
+        assert!(!task_output.panicked());
+
+        let task_output = GracefulTask::<()>::builder_default()
+            .spawn(async |_| panic!("boom"))
+            .await;
+        assert!(!task_output.finished_actively());
+        assert!(!task_output.was_cancelled());
+        assert!(task_output.panicked());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn into_result() {
+        let result = GracefulTask::builder_default()
+            .spawn(async |_| 42)
+            .await
+            .into_result();
+        assert_eq!(result.unwrap(), 42);
+
+        let result = GracefulTask::builder_default()
+            .spawn(async |shutdown| shutdown.await)
+            .graceful_shutdown()
+            .await
+            .into_result();
+        assert_eq!(result.unwrap(), GracefulKind::Explicit);
+
+        let result = GracefulTask::builder_default()
+            .spawn(async |_| panic!("boom"))
+            .await
+            .into_result();
+        match result {
+            Err(TaskFailure::Panicked(payload)) => {
+                assert_eq!(*payload.downcast::<&str>().unwrap(), "boom")
+            }
+            _ => unreachable!(),
+        }
+
+        let handle = tokio::spawn(std::future::pending::<()>());
+        handle.abort();
+        let task_output = TaskOutput {
+            finish_mode: FinishMode::Complete,
+            join_result: handle.await,
+            elapsed: Duration::ZERO,
+        };
+        assert!(matches!(
+            task_output.into_result(),
+            Err(TaskFailure::Cancelled)
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn elapsed() {
+        let graceful_task = GracefulTask::builder_default().spawn(async |shutdown| shutdown.await);
+        let elapsed = graceful_task.elapsed();
+        sleep().await;
+        assert!(graceful_task.elapsed() >= elapsed + Duration::from_millis(100));
+        let task_output = graceful_task.graceful_shutdown().await;
+        assert!(task_output.elapsed() >= Duration::from_millis(100));
+
+        let task_output = GracefulTask::builder_default()
+            .spawn(async |_| sleep_double().await)
+            .await;
+        assert_eq!(task_output.finish_mode, FinishMode::Complete);
+        assert!(task_output.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn try_trigger() {
+        let mut graceful_task =
+            GracefulTask::builder_default().spawn(async |shutdown| shutdown.await);
+        let trigger = graceful_task.shutdown_handle();
+        let guard = trigger.0.lock().unwrap();
+        assert_eq!(trigger.try_trigger(), TriggerResult::Contended);
+        drop(guard);
+        assert_eq!(trigger.try_trigger(), TriggerResult::Triggered);
+        assert_eq!(trigger.try_trigger(), TriggerResult::AlreadyTriggered);
+        let task_output = (&mut graceful_task).await;
+        assert_eq!(
+            task_output.finish_mode,
+            FinishMode::Shutdown(GracefulKind::Explicit)
+        );
+        assert_eq!(trigger.try_trigger(), TriggerResult::AlreadyTriggered);
+
+        let mut graceful_task = GracefulTask::builder_default().spawn(async |_| 42);
+        let trigger = graceful_task.shutdown_handle();
+        let task_output = (&mut graceful_task).await;
+        assert_eq!(task_output.finish_mode, FinishMode::Complete);
+        assert_eq!(trigger.try_trigger(), TriggerResult::Finished);
+        assert_eq!(trigger.try_trigger(), TriggerResult::Finished);
+        assert!(!trigger.trigger());
+
+        let graceful_task = GracefulTask::builder_default().spawn(async |shutdown| shutdown.await);
+        let trigger = graceful_task.shutdown_handle();
+        let poisoner = trigger.clone();
+        let result = std::thread::spawn(move || {
+            let _guard = poisoner.0.lock().unwrap();
+            panic!("poison the trigger");
+        })
+        .join();
+        assert!(result.is_err());
+        assert_eq!(trigger.try_trigger(), TriggerResult::Poisoned);
+        assert!(!trigger.trigger());
+        assert!(!graceful_task.is_finished());
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn shutdown_trigger() {
         let mut graceful_task =
@@ -0,0 +1,75 @@
+use tokio::task::{JoinError, JoinSet};
+
+/// [`JoinSet`] extension trait.
+pub trait JoinSetExt<T> {
+    /// Join all the tasks in the set (whether already completed or still pending), and collect
+    /// their results in the order they complete.
+    ///
+    /// After this resolves, the set is empty.
+    ///
+    /// Note: This method is not cancel safe. If the returned `Future` is dropped before it
+    /// resolves, the results that have already been joined will be lost.
+    fn drain_results(&mut self) -> impl Future<Output = Vec<Result<T, JoinError>>> + Send;
+}
+
+impl<T: Send + 'static> JoinSetExt<T> for JoinSet<T> {
+    async fn drain_results(&mut self) -> Vec<Result<T, JoinError>> {
+        let mut results = Vec::with_capacity(self.len());
+
+        while let Some(result) = self.join_next().await {
+            results.push(result);
+        }
+
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn drain_results() {
+        use std::time::Duration;
+        use tokio::time::sleep;
+
+        let mut set = JoinSet::new();
+        assert!(set.drain_results().await.is_empty());
+
+        for i in [3, 1, 4, 2, 0] {
+            set.spawn(async move {
+                sleep(Duration::from_millis(i * 100)).await;
+                i
+            });
+        }
+
+        let results = set.drain_results().await;
+        assert!(set.is_empty());
+        assert_eq!(
+            results.into_iter().map(Result::unwrap).collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4]
+        );
+
+        set.spawn(async { panic!("boom") });
+        set.spawn(async { 42 });
+        set.spawn(std::future::pending()).abort();
+
+        let results = set.drain_results().await;
+        assert_eq!(results.len(), 3);
+        assert_eq!(results.iter().filter(|r| matches!(r, Ok(42))).count(), 1);
+        assert_eq!(
+            results
+                .iter()
+                .filter(|r| r.as_ref().is_err_and(JoinError::is_panic))
+                .count(),
+            1
+        );
+        assert_eq!(
+            results
+                .iter()
+                .filter(|r| r.as_ref().is_err_and(JoinError::is_cancelled))
+                .count(),
+            1
+        );
+    }
+}
@@ -1,37 +1,318 @@
+/// A `serde` representation of [`ThreadId`] that is human-readable when possible.
+#[cfg(feature = "serde")]
+pub mod human_readable;
+
 use derive_more::Display;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::num::NonZeroU64;
+use std::{
+    collections::HashMap,
+    io,
+    num::{NonZeroU64, ParseIntError},
+    str::FromStr,
+    sync::{Mutex, MutexGuard, PoisonError},
+    thread::{Builder, JoinHandle},
+    time::{Duration, Instant},
+};
+#[cfg(feature = "sync")]
+use tokio::sync::oneshot;
 
 /// A [`ThreadId`] that can be `serde` and `Display`ed.
 ///
 /// [`ThreadId`]: std::thread::ThreadId
-#[derive(Debug, Display, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Display, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct ThreadId(pub NonZeroU64);
 
-#[cfg(feature = "serde")]
+impl ThreadId {
+    /// Returns the id as a primitive `u64`.
+    pub fn get(&self) -> u64 {
+        self.0.get()
+    }
+
+    /// Returns the id as a [`NonZeroU64`].
+    pub fn as_non_zero(&self) -> NonZeroU64 {
+        self.0
+    }
+
+    /// Check whether this id identifies the same thread as `std_id`.
+    ///
+    /// Since [`std::thread::ThreadId`] cannot be constructed from a number, this is the way to
+    /// correlate a stored id back to a live thread.
+    pub fn matches(&self, std_id: std::thread::ThreadId) -> bool {
+        *self == Self::from(std_id)
+    }
+}
+
+impl FromStr for ThreadId {
+    type Err = ParseIntError;
+
+    /// Parse a decimal non-zero integer (the `Display` format of [`ThreadId`]).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
 impl From<std::thread::ThreadId> for ThreadId {
     fn from(value: std::thread::ThreadId) -> Self {
-        #[derive(Deserialize)]
-        #[serde(rename = "ThreadId")]
-        struct Inner(NonZeroU64);
+        ThreadId::from_debug(&format!("{:?}", value)).expect("Should always success")
+    }
+}
+
+impl ThreadId {
+    /// Parse the `Debug` format (`ThreadId(N)`) shared with [`std::thread::ThreadId`].
+    fn from_debug(s: &str) -> Option<Self> {
+        s.strip_prefix("ThreadId(")?.strip_suffix(')')?.parse().ok()
+    }
+}
+
+/// The identity of a thread, capturing its [`ThreadId`] and name together (can be `serde`).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NamedThread {
+    /// Id of the thread.
+    pub id: ThreadId,
+    /// Name of the thread, `None` if it is unnamed.
+    pub name: Option<String>,
+}
+
+impl NamedThread {
+    /// Capture the identity of the current thread.
+    pub fn current() -> Self {
+        let thread = std::thread::current();
+
+        Self {
+            id: thread.id().into(),
+            name: thread.name().map(ToOwned::to_owned),
+        }
+    }
+}
+
+/// The stack size (8 MiB) used by [`spawn_big`].
+pub const BIG_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Spawn a new thread with a stack of [`BIG_STACK_SIZE`] bytes instead of the default (2 MiB at
+/// the time of writing), to avoid stack overflows in deeply-recursive workloads.
+///
+/// This is a shorthand for `spawn_big_with(BIG_STACK_SIZE, f)`. The thread is spawned from a
+/// fresh [`Builder`], so the `RUST_MIN_STACK` environment variable does not apply.
+///
+/// See: [`std::thread::Builder::spawn`]
+pub fn spawn_big<F, T>(f: F) -> io::Result<JoinHandle<T>>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    spawn_big_with(BIG_STACK_SIZE, f)
+}
+
+/// Spawn a new thread with a stack of `stack_size` bytes.
+///
+/// This is a shorthand for `std::thread::Builder::new().stack_size(stack_size).spawn(f)`.
+///
+/// See: [`std::thread::Builder::spawn`]
+pub fn spawn_big_with<F, T>(stack_size: usize, f: F) -> io::Result<JoinHandle<T>>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    Builder::new().stack_size(stack_size).spawn(f)
+}
+
+/// Run `f` on each item in parallel (one scoped thread per item), and collect the results in the
+/// input order.
+///
+/// If any of the threads panics, the panic is propagated to the caller after all the threads have
+/// finished.
+///
+/// See: [`std::thread::scope`]
+pub fn scoped_map<T, R, F>(items: Vec<T>, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let f = &f;
+
+    std::thread::scope(|s| {
+        let handles: Vec<_> = items
+            .into_iter()
+            .map(|item| s.spawn(move || f(item)))
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+            })
+            .collect()
+    })
+}
+
+/// A registry of per-thread values keyed by [`ThreadId`].
+///
+/// Unlike [`thread_local!`], the values of all threads can be enumerated (and serialized, since
+/// [`ThreadId`] can be `serde`), which is useful for diagnostics that dump the state of all
+/// threads.
+///
+/// Values are not removed automatically when their threads exit, use [`remove_current`] for that.
+///
+/// [`remove_current`]: ThreadLocalRegistry::remove_current
+#[derive(Debug)]
+pub struct ThreadLocalRegistry<V>(Mutex<HashMap<ThreadId, V>>);
+
+impl<V> Default for ThreadLocalRegistry<V> {
+    fn default() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+}
+
+impl<V> ThreadLocalRegistry<V> {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a clone of the value of the current thread, initializing it with `init` first if
+    /// it does not exist.
+    pub fn get_or_init<F: FnOnce() -> V>(&self, init: F) -> V
+    where
+        V: Clone,
+    {
+        self.lock().entry(current_id()).or_insert_with(init).clone()
+    }
+
+    /// Remove the value of the current thread, returning it if it exists.
+    pub fn remove_current(&self) -> Option<V> {
+        self.lock().remove(&current_id())
+    }
+
+    /// Returns a snapshot of the values of all threads.
+    pub fn snapshot(&self) -> HashMap<ThreadId, V>
+    where
+        V: Clone,
+    {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<ThreadId, V>> {
+        // The map is never left in an inconsistent state, so it is fine to ignore poisoning.
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Park the current thread until `cond` returns true or `timeout` elapses, returning whether the
+/// condition was met.
+///
+/// `cond` is checked before parking and re-checked after every wakeup, so spurious wakeups are
+/// handled correctly. The thread that makes `cond` true should [`unpark`] this thread afterwards,
+/// otherwise the change will only be noticed when the timeout elapses.
+///
+/// [`unpark`]: std::thread::Thread::unpark
+///
+/// See: [`std::thread::park_timeout`]
+pub fn park_until<F: FnMut() -> bool>(mut cond: F, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        if cond() {
+            return true;
+        }
+
+        let remaining = deadline.saturating_duration_since(Instant::now());
+
+        if remaining.is_zero() {
+            return false;
+        }
+
+        std::thread::park_timeout(remaining);
+    }
+}
+
+/// Returns the [`ThreadId`] of the current thread.
+///
+/// This is a shorthand for `ThreadId::from(std::thread::current().id())`.
+pub fn current_id() -> ThreadId {
+    std::thread::current().id().into()
+}
+
+/// Spawn a new named thread, returning an [`io::Result`] instead of panicking if the OS fails to
+/// create a thread.
+///
+/// This is a shorthand for `std::thread::Builder::new().name(name).spawn(f)`.
+///
+/// See: [`std::thread::Builder::spawn`]
+pub fn spawn_named<F, T>(name: impl Into<String>, f: F) -> io::Result<JoinHandle<T>>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    Builder::new().name(name.into()).spawn(f)
+}
+
+/// Wait for the thread to finish with a timeout.
+///
+/// Returns `Ok` with the return value of the thread if it finishes within `timeout`, otherwise
+/// returns `Err` with the handle back, so that it can be joined (or waited) again later.
+///
+/// Since [`JoinHandle`] has no native timeout, this polls [`JoinHandle::is_finished`] until the
+/// deadline, sleeping at most 1 millisecond between polls (so the calling thread is blocked for
+/// up to `timeout`, and wakes up about once per millisecond meanwhile).
+///
+/// # Panics
+///
+/// If the thread panicked, the panic is resumed on the calling thread with the same payload
+/// (see [`std::panic::resume_unwind`]).
+pub fn join_timeout<T>(handle: JoinHandle<T>, timeout: Duration) -> Result<T, JoinHandle<T>> {
+    const INTERVAL: Duration = Duration::from_millis(1);
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        if handle.is_finished() {
+            return Ok(handle
+                .join()
+                .unwrap_or_else(|payload| std::panic::resume_unwind(payload)));
+        }
 
-        Self(
-            ron::from_str::<Inner>(&format!("{:?}", value))
-                .expect("Should always success")
-                .0,
-        )
+        let remaining = deadline.saturating_duration_since(Instant::now());
+
+        if remaining.is_zero() {
+            return Err(handle);
+        }
+
+        std::thread::sleep(remaining.min(INTERVAL));
     }
 }
 
+/// Spawn a new thread, returning its result through a [`oneshot::Receiver`] (requires the `sync`
+/// feature flag).
+///
+/// The receiver can be `.await`ed in asynchronous code (or [`blocking_recv`]ed in synchronous
+/// code) without joining the thread. If the thread panics, the receiver resolves to an error, and
+/// the panic can be obtained by joining the returned handle.
+///
+/// [`blocking_recv`]: oneshot::Receiver::blocking_recv
+#[cfg(feature = "sync")]
+pub fn spawn_with_result<F, T>(f: F) -> (JoinHandle<()>, oneshot::Receiver<T>)
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (sender, receiver) = oneshot::channel();
+    let handle = std::thread::spawn(move || {
+        sender.send(f()).ok();
+    });
+
+    (handle, receiver)
+}
+
 #[cfg(test)]
 mod tests {
-    #[cfg(feature = "serde")]
     use super::*;
 
     #[test]
-    #[cfg(feature = "serde")]
     fn from_std_thread_id() {
         let id = std::thread::current().id();
         let thread_id = ThreadId::from(id);
@@ -40,4 +321,237 @@ mod tests {
         assert_eq!(debug, format!("{:?}", thread_id));
         assert_eq!(debug, format!("ThreadId({})", thread_id));
     }
+
+    #[test]
+    fn from_str() {
+        use std::num::IntErrorKind;
+
+        let thread_id = ThreadId(NonZeroU64::new(42).unwrap());
+        assert_eq!(
+            thread_id.to_string().parse::<ThreadId>().unwrap(),
+            thread_id
+        );
+
+        let err = "0".parse::<ThreadId>().unwrap_err();
+        assert_eq!(err.kind(), &IntErrorKind::Zero);
+        let err = "abc".parse::<ThreadId>().unwrap_err();
+        assert_eq!(err.kind(), &IntErrorKind::InvalidDigit);
+        let err = "".parse::<ThreadId>().unwrap_err();
+        assert_eq!(err.kind(), &IntErrorKind::Empty);
+    }
+
+    #[test]
+    fn named_thread() {
+        let named_thread = Builder::new()
+            .name("worker".into())
+            .spawn(NamedThread::current)
+            .unwrap()
+            .join()
+            .unwrap();
+        assert_eq!(named_thread.name.as_deref(), Some("worker"));
+        assert_ne!(named_thread.id, super::current_id());
+
+        let named_thread = std::thread::spawn(|| (NamedThread::current(), super::current_id()))
+            .join()
+            .unwrap();
+        assert_eq!(named_thread.0.name, None);
+        assert_eq!(named_thread.0.id, named_thread.1);
+    }
+
+    #[test]
+    fn current_id() {
+        assert_eq!(
+            super::current_id(),
+            ThreadId::from(std::thread::current().id())
+        );
+
+        let main_id = super::current_id();
+        let spawned_id = std::thread::spawn(super::current_id).join().unwrap();
+        assert_ne!(main_id, spawned_id);
+    }
+
+    #[test]
+    fn spawn_named() {
+        let handle = super::spawn_named("worker", || {
+            std::thread::current().name().map(ToOwned::to_owned)
+        })
+        .unwrap();
+        assert_eq!(handle.thread().name(), Some("worker"));
+        assert_eq!(handle.join().unwrap().as_deref(), Some("worker"));
+    }
+
+    #[test]
+    fn ord() {
+        let mut ids: Vec<_> = [3, 10, 1, 2]
+            .into_iter()
+            .map(|id| ThreadId(NonZeroU64::new(id).unwrap()))
+            .collect();
+        ids.sort();
+        assert_eq!(
+            ids.iter().map(ThreadId::get).collect::<Vec<_>>(),
+            [1, 2, 3, 10]
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "sync")]
+    async fn spawn_with_result() {
+        let (handle, receiver) = super::spawn_with_result(|| 42);
+        assert_eq!(receiver.await.unwrap(), 42);
+        handle.join().unwrap();
+
+        let (handle, receiver) = super::spawn_with_result(|| -> i32 { panic!("boom") });
+        assert!(receiver.await.is_err());
+        assert!(handle.join().is_err());
+    }
+
+    #[test]
+    fn join_timeout() {
+        let handle = std::thread::spawn(|| 42);
+        let result = super::join_timeout(handle, Duration::from_secs(1));
+        assert_eq!(result.unwrap(), 42);
+
+        let handle = std::thread::spawn(|| {
+            std::thread::sleep(Duration::from_millis(200));
+            42
+        });
+        let handle = super::join_timeout(handle, Duration::from_millis(10)).unwrap_err();
+        assert!(!handle.is_finished());
+        let result = super::join_timeout(handle, Duration::from_secs(1));
+        assert_eq!(result.unwrap(), 42);
+    }
+
+    #[test]
+    fn join_timeout_panic() {
+        let handle = std::thread::spawn(|| std::panic::panic_any(29u8));
+        let payload = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            super::join_timeout(handle, Duration::from_secs(1)).ok();
+        }))
+        .unwrap_err();
+        assert_eq!(payload.downcast_ref::<u8>(), Some(&29));
+    }
+
+    #[test]
+    fn matches() {
+        let thread_id = super::current_id();
+        assert!(thread_id.matches(std::thread::current().id()));
+
+        let std_id = std::thread::spawn(|| std::thread::current().id())
+            .join()
+            .unwrap();
+        assert!(!thread_id.matches(std_id));
+    }
+
+    /// Recurse until at least `target` bytes of stack below `base` have been used.
+    fn use_stack(base: usize, target: usize) -> usize {
+        let frame = [1u8; 16 * 1024];
+        let here = std::hint::black_box(&frame).as_ptr() as usize;
+
+        if base.abs_diff(here) >= target {
+            return 0;
+        }
+
+        use_stack(base, target) + std::hint::black_box(&frame)[0] as usize
+    }
+
+    fn stack_base() -> usize {
+        let local = 0u8;
+        std::hint::black_box(&local) as *const u8 as usize
+    }
+
+    #[test]
+    fn spawn_big() {
+        // Past the 2 MiB default, within `BIG_STACK_SIZE`.
+        let handle = super::spawn_big(|| use_stack(stack_base(), 4 * 1024 * 1024)).unwrap();
+        assert!(handle.join().is_ok());
+    }
+
+    #[test]
+    fn spawn_big_with() {
+        // Past `BIG_STACK_SIZE`, within the explicit size.
+        let handle = super::spawn_big_with(BIG_STACK_SIZE * 2, || {
+            use_stack(stack_base(), BIG_STACK_SIZE + 4 * 1024 * 1024)
+        })
+        .unwrap();
+        assert!(handle.join().is_ok());
+    }
+
+    #[test]
+    fn scoped_map() {
+        let results = super::scoped_map(vec![3, 1, 2], |n| {
+            std::thread::sleep(Duration::from_millis(n * 10));
+            n * 2
+        });
+        assert_eq!(results, [6, 2, 4]);
+
+        // Every item waits for all the others, so this only finishes if they run in parallel.
+        let barrier = std::sync::Barrier::new(4);
+        let results = super::scoped_map(vec![1u64, 2, 3, 4], |n| {
+            barrier.wait();
+            (0..1_000_000).fold(n, |acc, i| acc.wrapping_add(i))
+        });
+        assert_eq!(results.len(), 4);
+
+        assert!(super::scoped_map(Vec::<i32>::new(), |n| n).is_empty());
+    }
+
+    #[test]
+    fn thread_local_registry() {
+        let registry = ThreadLocalRegistry::new();
+        assert_eq!(registry.get_or_init(|| "main"), "main");
+        assert_eq!(registry.get_or_init(|| "again"), "main");
+
+        let worker_id = std::thread::scope(|s| {
+            s.spawn(|| {
+                assert_eq!(registry.get_or_init(|| "worker"), "worker");
+                super::current_id()
+            })
+            .join()
+            .unwrap()
+        });
+
+        let snapshot = registry.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[&super::current_id()], "main");
+        assert_eq!(snapshot[&worker_id], "worker");
+
+        assert_eq!(registry.remove_current(), Some("main"));
+        assert_eq!(registry.remove_current(), None);
+        assert_eq!(registry.snapshot().len(), 1);
+    }
+
+    #[test]
+    fn park_until() {
+        use std::sync::{
+            Arc,
+            atomic::{AtomicBool, Ordering},
+        };
+
+        let flag = Arc::new(AtomicBool::new(false));
+        let thread = std::thread::current();
+        let setter = {
+            let flag = flag.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(50));
+                flag.store(true, Ordering::Release);
+                thread.unpark();
+            })
+        };
+        assert!(super::park_until(
+            || flag.load(Ordering::Acquire),
+            Duration::from_secs(10)
+        ));
+        setter.join().unwrap();
+
+        let start = Instant::now();
+        assert!(!super::park_until(|| false, Duration::from_millis(50)));
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+
+    #[test]
+    fn get() {
+        let thread_id = ThreadId(NonZeroU64::new(42).unwrap());
+        assert_eq!(thread_id.get(), 42);
+        assert_eq!(thread_id.as_non_zero(), NonZeroU64::new(42).unwrap());
+    }
 }
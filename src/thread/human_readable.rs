@@ -0,0 +1,81 @@
+//! Use this module with `#[serde(with = "est::thread::human_readable")]` on a [`ThreadId`] field,
+//! to serialize it as the `"ThreadId(N)"` string for human-readable formats (such as JSON or RON),
+//! while staying numeric for compact binary formats (such as bincode).
+//!
+//! The human-readable representation matches the `Debug` output of [`std::thread::ThreadId`],
+//! which makes the serialized data easier to correlate with logs.
+//!
+//! # Examples
+//!
+//! ```
+//! use est::thread::ThreadId;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "est::thread::human_readable")]
+//!     thread: ThreadId,
+//! }
+//! ```
+
+use super::ThreadId;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+use std::num::NonZeroU64;
+
+/// Serialize a [`ThreadId`] as `"ThreadId(N)"` for human-readable formats, or as a number
+/// otherwise.
+pub fn serialize<S: Serializer>(id: &ThreadId, serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.collect_str(&format_args!("{:?}", id))
+    } else {
+        id.0.serialize(serializer)
+    }
+}
+
+/// Deserialize a [`ThreadId`] from `"ThreadId(N)"` for human-readable formats, or from a number
+/// otherwise.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ThreadId, D::Error> {
+    if deserializer.is_human_readable() {
+        let s = String::deserialize(deserializer)?;
+        ThreadId::from_debug(&s)
+            .ok_or_else(|| D::Error::custom(format!("invalid thread id: {:?}", s)))
+    } else {
+        NonZeroU64::deserialize(deserializer).map(ThreadId)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "super")]
+        thread: ThreadId,
+    }
+
+    fn record() -> Record {
+        Record {
+            thread: ThreadId(NonZeroU64::new(42).unwrap()),
+        }
+    }
+
+    #[test]
+    fn json() {
+        let json = serde_json::to_string(&record()).unwrap();
+        assert_eq!(json, r#"{"thread":"ThreadId(42)"}"#);
+        assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record());
+
+        assert!(serde_json::from_str::<Record>(r#"{"thread":"42"}"#).is_err());
+        assert!(serde_json::from_str::<Record>(r#"{"thread":"ThreadId(0)"}"#).is_err());
+    }
+
+    #[test]
+    fn bincode() {
+        let config = bincode::config::standard();
+        let bytes = bincode::serde::encode_to_vec(record(), config).unwrap();
+        assert_eq!(bytes, bincode::serde::encode_to_vec(42u64, config).unwrap());
+        let (decoded, _) = bincode::serde::decode_from_slice::<Record, _>(&bytes, config).unwrap();
+        assert_eq!(decoded, record());
+    }
+}